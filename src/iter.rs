@@ -0,0 +1,590 @@
+use alloc::collections::vec_deque;
+use alloc::collections::VecDeque;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::iter::FusedIterator;
+use core::slice;
+
+/// iterator over references to the elements of an array, front to back
+pub struct Iter<'a, T> {
+    /// rows not yet started from either end
+    rows: slice::Iter<'a, VecDeque<T>>,
+    /// row currently being consumed from the front
+    front: vec_deque::Iter<'a, T>,
+    /// row currently being consumed from the back
+    back: vec_deque::Iter<'a, T>,
+    /// number of elements not yet yielded
+    remaining: usize,
+}
+
+impl<'a, T> Iter<'a, T> {
+    /// iterate over the elements from `start` up to `end` in the given rows
+    pub(crate) fn new(
+        rows: &'a [VecDeque<T>],
+        row_capacity: usize,
+        start: usize,
+        end: usize,
+    ) -> Self {
+        if start == end {
+            return Iter {
+                rows: [].iter(),
+                front: Default::default(),
+                back: Default::default(),
+                remaining: 0,
+            };
+        }
+
+        let (first_row, first_column) = (start / row_capacity, start % row_capacity);
+        let (last_row, last_column) = ((end - 1) / row_capacity, (end - 1) % row_capacity);
+
+        if first_row == last_row {
+            Iter {
+                rows: [].iter(),
+                front: rows[first_row].range(first_column..=last_column),
+                back: Default::default(),
+                remaining: end - start,
+            }
+        } else {
+            Iter {
+                rows: rows[(first_row + 1)..last_row].iter(),
+                front: rows[first_row].range(first_column..),
+                back: rows[last_row].range(..=last_column),
+                remaining: end - start,
+            }
+        }
+    }
+}
+
+impl<T> Clone for Iter<'_, T> {
+    fn clone(&self) -> Self {
+        Iter {
+            rows: self.rows.clone(),
+            front: self.front.clone(),
+            back: self.back.clone(),
+            remaining: self.remaining,
+        }
+    }
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        loop {
+            if let Some(element) = self.front.next() {
+                self.remaining -= 1;
+                return Some(element);
+            }
+
+            match self.rows.next() {
+                Some(row) => self.front = row.iter(),
+                None => break,
+            }
+        }
+
+        // all other rows are exhausted, finish off the back row
+        let element = self.back.next()?;
+        self.remaining -= 1;
+        Some(element)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+
+    fn nth(&mut self, mut n: usize) -> Option<&'a T> {
+        if n >= self.remaining {
+            self.rows = Default::default();
+            self.front = Default::default();
+            self.back = Default::default();
+            self.remaining = 0;
+            return None;
+        }
+
+        // skip whole rows without visiting their elements
+        loop {
+            if n < self.front.len() {
+                self.remaining -= n + 1;
+                return self.front.nth(n);
+            }
+
+            n -= self.front.len();
+            self.remaining -= self.front.len();
+            self.front = Default::default();
+            match self.rows.next() {
+                Some(row) => self.front = row.iter(),
+                None => break,
+            }
+        }
+
+        self.remaining -= n + 1;
+        self.back.nth(n)
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for Iter<'a, T> {
+    fn next_back(&mut self) -> Option<&'a T> {
+        loop {
+            if let Some(element) = self.back.next_back() {
+                self.remaining -= 1;
+                return Some(element);
+            }
+
+            match self.rows.next_back() {
+                Some(row) => self.back = row.iter(),
+                None => break,
+            }
+        }
+
+        // all other rows are exhausted, finish off the front row
+        let element = self.front.next_back()?;
+        self.remaining -= 1;
+        Some(element)
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+impl<T> FusedIterator for Iter<'_, T> {}
+
+/// iterator over every `step`-th element of an array, starting with the first
+pub struct Stride<'a, T> {
+    /// rows of the array
+    rows: &'a [VecDeque<T>],
+    /// number of elements in each full row
+    row_capacity: usize,
+    /// index of the next element to yield from the front
+    front: usize,
+    /// distance between yielded elements
+    step: usize,
+    /// number of elements not yet yielded
+    remaining: usize,
+}
+
+impl<'a, T> Stride<'a, T> {
+    /// iterate over every `step`-th of the first `length` elements of the given rows
+    pub(crate) fn new(
+        rows: &'a [VecDeque<T>],
+        row_capacity: usize,
+        length: usize,
+        step: usize,
+    ) -> Self {
+        Stride {
+            rows,
+            row_capacity,
+            front: 0,
+            step,
+            remaining: length.div_ceil(step),
+        }
+    }
+
+    /// the element at the given index of the array
+    fn element(&self, index: usize) -> &'a T {
+        &self.rows[index / self.row_capacity][index % self.row_capacity]
+    }
+}
+
+impl<T> Clone for Stride<'_, T> {
+    fn clone(&self) -> Self {
+        Stride { ..*self }
+    }
+}
+
+impl<'a, T> Iterator for Stride<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        let element = self.element(self.front);
+        self.front += self.step;
+        self.remaining -= 1;
+
+        Some(element)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for Stride<'a, T> {
+    fn next_back(&mut self) -> Option<&'a T> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        self.remaining -= 1;
+        Some(self.element(self.front + self.remaining * self.step))
+    }
+}
+
+impl<T> ExactSizeIterator for Stride<'_, T> {}
+impl<T> FusedIterator for Stride<'_, T> {}
+
+/// iterator over mutable references to the elements of an array, front to back
+pub struct IterMut<'a, T> {
+    /// rows not yet started from either end
+    rows: slice::IterMut<'a, VecDeque<T>>,
+    /// row currently being consumed from the front
+    front: vec_deque::IterMut<'a, T>,
+    /// row currently being consumed from the back
+    back: vec_deque::IterMut<'a, T>,
+    /// number of elements not yet yielded
+    remaining: usize,
+}
+
+impl<'a, T> IterMut<'a, T> {
+    /// iterate over every element of the given rows
+    pub(crate) fn new(rows: &'a mut [VecDeque<T>], length: usize) -> Self {
+        IterMut {
+            rows: rows.iter_mut(),
+            front: Default::default(),
+            back: Default::default(),
+            remaining: length,
+        }
+    }
+
+    /// iterate over the elements from `start` up to `end` in the given rows
+    pub(crate) fn range(
+        rows: &'a mut [VecDeque<T>],
+        row_capacity: usize,
+        start: usize,
+        end: usize,
+    ) -> Self {
+        if start == end {
+            return IterMut::new(&mut [], 0);
+        }
+
+        let (first_row, first_column) = (start / row_capacity, start % row_capacity);
+        let (last_row, last_column) = ((end - 1) / row_capacity, (end - 1) % row_capacity);
+
+        if first_row == last_row {
+            IterMut {
+                rows: [].iter_mut(),
+                front: rows[first_row].range_mut(first_column..=last_column),
+                back: Default::default(),
+                remaining: end - start,
+            }
+        } else {
+            let (head, tail) = rows.split_at_mut(last_row);
+            let (first, middle) = head[first_row..].split_first_mut().unwrap();
+
+            IterMut {
+                rows: middle.iter_mut(),
+                front: first.range_mut(first_column..),
+                back: tail[0].range_mut(..=last_column),
+                remaining: end - start,
+            }
+        }
+    }
+}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<&'a mut T> {
+        loop {
+            if let Some(element) = self.front.next() {
+                self.remaining -= 1;
+                return Some(element);
+            }
+
+            match self.rows.next() {
+                Some(row) => self.front = row.iter_mut(),
+                None => break,
+            }
+        }
+
+        // all other rows are exhausted, finish off the back row
+        let element = self.back.next()?;
+        self.remaining -= 1;
+        Some(element)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+
+    fn nth(&mut self, mut n: usize) -> Option<&'a mut T> {
+        if n >= self.remaining {
+            self.rows = Default::default();
+            self.front = Default::default();
+            self.back = Default::default();
+            self.remaining = 0;
+            return None;
+        }
+
+        // skip whole rows without visiting their elements
+        loop {
+            if n < self.front.len() {
+                self.remaining -= n + 1;
+                return self.front.nth(n);
+            }
+
+            n -= self.front.len();
+            self.remaining -= self.front.len();
+            self.front = Default::default();
+            match self.rows.next() {
+                Some(row) => self.front = row.iter_mut(),
+                None => break,
+            }
+        }
+
+        self.remaining -= n + 1;
+        self.back.nth(n)
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for IterMut<'a, T> {
+    fn next_back(&mut self) -> Option<&'a mut T> {
+        loop {
+            if let Some(element) = self.back.next_back() {
+                self.remaining -= 1;
+                return Some(element);
+            }
+
+            match self.rows.next_back() {
+                Some(row) => self.back = row.iter_mut(),
+                None => break,
+            }
+        }
+
+        // all other rows are exhausted, finish off the front row
+        let element = self.front.next_back()?;
+        self.remaining -= 1;
+        Some(element)
+    }
+}
+
+impl<T> ExactSizeIterator for IterMut<'_, T> {}
+impl<T> FusedIterator for IterMut<'_, T> {}
+
+/// iterator over the rows of an array, each yielding its elements in order
+pub struct Rows<'a, T> {
+    /// rows not yet yielded
+    rows: slice::Iter<'a, VecDeque<T>>,
+}
+
+impl<'a, T> Rows<'a, T> {
+    /// iterate over the given rows in use
+    pub(crate) fn new(rows: &'a [VecDeque<T>]) -> Self {
+        Rows { rows: rows.iter() }
+    }
+}
+
+impl<T> Clone for Rows<'_, T> {
+    fn clone(&self) -> Self {
+        Rows {
+            rows: self.rows.clone(),
+        }
+    }
+}
+
+impl<'a, T> Iterator for Rows<'a, T> {
+    type Item = vec_deque::Iter<'a, T>;
+
+    fn next(&mut self) -> Option<vec_deque::Iter<'a, T>> {
+        self.rows.next().map(VecDeque::iter)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.rows.size_hint()
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for Rows<'a, T> {
+    fn next_back(&mut self) -> Option<vec_deque::Iter<'a, T>> {
+        self.rows.next_back().map(VecDeque::iter)
+    }
+}
+
+impl<T> ExactSizeIterator for Rows<'_, T> {}
+impl<T> FusedIterator for Rows<'_, T> {}
+
+/// iterator over the rows of an array in use, each as a mutable slice
+pub struct RowsMut<'a, T> {
+    /// rows not yet yielded
+    rows: slice::IterMut<'a, VecDeque<T>>,
+}
+
+impl<'a, T> RowsMut<'a, T> {
+    /// iterate over the given rows in use
+    pub(crate) fn new(rows: &'a mut [VecDeque<T>]) -> Self {
+        RowsMut {
+            rows: rows.iter_mut(),
+        }
+    }
+}
+
+impl<'a, T> Iterator for RowsMut<'a, T> {
+    type Item = &'a mut [T];
+
+    fn next(&mut self) -> Option<&'a mut [T]> {
+        self.rows.next().map(VecDeque::make_contiguous)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.rows.size_hint()
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for RowsMut<'a, T> {
+    fn next_back(&mut self) -> Option<&'a mut [T]> {
+        self.rows.next_back().map(VecDeque::make_contiguous)
+    }
+}
+
+impl<T> ExactSizeIterator for RowsMut<'_, T> {}
+impl<T> FusedIterator for RowsMut<'_, T> {}
+
+/// iterator that moves the elements out of an array, front to back
+pub struct IntoIter<T> {
+    /// rows not yet started from either end
+    rows: vec::IntoIter<VecDeque<T>>,
+    /// row currently being consumed from the front
+    front: vec_deque::IntoIter<T>,
+    /// row currently being consumed from the back
+    back: vec_deque::IntoIter<T>,
+    /// number of elements not yet yielded
+    remaining: usize,
+}
+
+impl<T> IntoIter<T> {
+    /// iterate over every element of the given rows
+    pub(crate) fn new(rows: Vec<VecDeque<T>>, length: usize) -> Self {
+        IntoIter {
+            rows: rows.into_iter(),
+            front: VecDeque::new().into_iter(),
+            back: VecDeque::new().into_iter(),
+            remaining: length,
+        }
+    }
+}
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        loop {
+            if let Some(element) = self.front.next() {
+                self.remaining -= 1;
+                return Some(element);
+            }
+
+            match self.rows.next() {
+                Some(row) => self.front = row.into_iter(),
+                None => break,
+            }
+        }
+
+        // all other rows are exhausted, finish off the back row
+        let element = self.back.next()?;
+        self.remaining -= 1;
+        Some(element)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T> DoubleEndedIterator for IntoIter<T> {
+    fn next_back(&mut self) -> Option<T> {
+        loop {
+            if let Some(element) = self.back.next_back() {
+                self.remaining -= 1;
+                return Some(element);
+            }
+
+            match self.rows.next_back() {
+                Some(row) => self.back = row.into_iter(),
+                None => break,
+            }
+        }
+
+        // all other rows are exhausted, finish off the front row
+        let element = self.front.next_back()?;
+        self.remaining -= 1;
+        Some(element)
+    }
+}
+
+impl<T> ExactSizeIterator for IntoIter<T> {}
+impl<T> FusedIterator for IntoIter<T> {}
+
+/// iterator that moves the rows out of an array, each as a `Vec` of its elements
+pub struct IntoRows<T> {
+    /// rows not yet yielded
+    rows: vec::IntoIter<VecDeque<T>>,
+}
+
+impl<T> IntoRows<T> {
+    /// iterate over the given rows in use
+    pub(crate) fn new(rows: Vec<VecDeque<T>>) -> Self {
+        IntoRows {
+            rows: rows.into_iter(),
+        }
+    }
+}
+
+impl<T> Iterator for IntoRows<T> {
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Vec<T>> {
+        self.rows.next().map(Vec::from)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.rows.size_hint()
+    }
+}
+
+impl<T> DoubleEndedIterator for IntoRows<T> {
+    fn next_back(&mut self) -> Option<Vec<T>> {
+        self.rows.next_back().map(Vec::from)
+    }
+}
+
+impl<T> ExactSizeIterator for IntoRows<T> {}
+impl<T> FusedIterator for IntoRows<T> {}
+
+/// iterator over elements removed from an array
+///
+/// the elements are removed from the array as soon as it is created,
+/// so dropping it early still leaves them removed
+pub struct Drain<T> {
+    /// the removed elements, front to back
+    elements: vec::IntoIter<T>,
+}
+
+impl<T> Drain<T> {
+    /// yield the given removed elements in order
+    pub(crate) fn new(elements: Vec<T>) -> Self {
+        Drain {
+            elements: elements.into_iter(),
+        }
+    }
+}
+
+impl<T> Iterator for Drain<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.elements.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.elements.size_hint()
+    }
+}
+
+impl<T> DoubleEndedIterator for Drain<T> {
+    fn next_back(&mut self) -> Option<T> {
+        self.elements.next_back()
+    }
+}
+
+impl<T> ExactSizeIterator for Drain<T> {}
+impl<T> FusedIterator for Drain<T> {}
@@ -1,10 +1,40 @@
 // TODO: use a custom Deque based on slices instead of Vec
 // that way we can use a single Vec instead of one per row
-use std::collections::VecDeque;
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
+
+use alloc::boxed::Box;
+use alloc::collections::{LinkedList, TryReserveError, VecDeque};
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::iter::{FromIterator, Product, Sum};
+use core::mem;
+use core::ops::{Bound, Index, IndexMut, Range, RangeBounds};
+
+mod iter;
+#[cfg(feature = "rayon")]
+mod rayon_impl;
+#[cfg(feature = "serde")]
+mod serde_impl;
+mod slice;
+
+pub use iter::{Drain, IntoIter, IntoRows, Iter, IterMut, Rows, RowsMut, Stride};
+#[cfg(feature = "rayon")]
+pub use rayon_impl::{ParIter, ParIterMut};
+pub use slice::{Chunks, IgushSlice, Windows};
 
 /// Array with Constant Time Access and Fast Insertion and Deletion
 /// compromise in performance between array and list
-#[derive(Debug)]
+///
+/// every row before the last one in use is kept full, so an element is always
+/// at `index / row_capacity` and `index % row_capacity`; this is also why
+/// insertion and removal only ever shift elements toward the back of the array,
+/// as there is no room at the front to shift into
 pub struct Igush<T> {
     /// backing storage of the structure
     backing: Vec<VecDeque<T>>,
@@ -17,18 +47,54 @@ pub struct Igush<T> {
     rows: usize,
     /// current number of elements in array
     length: usize,
+    /// whether pushes should widen the rows as the array grows
+    adaptive: bool,
+}
+
+/// row capacity used by `Igush::new` and `Default`
+pub const DEFAULT_ROW_CAPACITY: usize = 10;
+
+/// row capacity to use for an array expected to hold `length` elements
+fn auto_row_capacity(length: usize) -> usize {
+    length.isqrt().max(DEFAULT_ROW_CAPACITY)
+}
+
+/// error returned when trying to create an array with a row capacity of zero
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ZeroRowCapacity;
+
+impl fmt::Display for ZeroRowCapacity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("row capacity must be non-zero")
+    }
+}
+
+impl core::error::Error for ZeroRowCapacity {}
+
+/// error returned when trying to assemble an array from rows that don't form a valid layout
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvalidLayout;
+
+impl fmt::Display for InvalidLayout {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("rows do not form a valid layout")
+    }
 }
 
+impl core::error::Error for InvalidLayout {}
+
 impl<T> Igush<T> {
     /// create a new array with the given row width and total capacity
+    ///
+    /// exactly `total_capacity.div_ceil(row_capacity)` DEQs are preallocated,
+    /// so pushing up to `total_capacity` elements never reallocates
+    ///
+    /// panics if `row_capacity` is zero
     pub fn with_row_capacity(row_capacity: usize, total_capacity: usize) -> Igush<T> {
+        assert!(row_capacity > 0, "row capacity must be non-zero");
+
         // number of rows
-        let rows = total_capacity / row_capacity
-            + if total_capacity % row_capacity > 0 {
-                1
-            } else {
-                0
-            };
+        let rows = total_capacity.div_ceil(row_capacity);
 
         let backing: Vec<VecDeque<T>> = (0..rows)
             .map(|_| VecDeque::with_capacity(row_capacity))
@@ -41,36 +107,266 @@ impl<T> Igush<T> {
             capacity: actual_total_capacity,
             rows: 0,
             length: 0,
+            adaptive: false,
+        }
+    }
+
+    /// create a new array with the given row width and total capacity,
+    /// returning an error instead of panicking if `row_capacity` is zero
+    pub fn try_with_row_capacity(
+        row_capacity: usize,
+        total_capacity: usize,
+    ) -> Result<Igush<T>, ZeroRowCapacity> {
+        if row_capacity == 0 {
+            return Err(ZeroRowCapacity);
+        }
+
+        Ok(Self::with_row_capacity(row_capacity, total_capacity))
+    }
+
+    /// assemble an array from its rows without checking their layout
+    ///
+    /// # Safety
+    ///
+    /// `row_capacity` must be non-zero, and every row before the last one
+    /// holding elements must hold exactly `row_capacity` elements, the last one
+    /// holding elements must hold at most `row_capacity` elements, and every row
+    /// after it must be empty
+    ///
+    /// breaking any of these is undefined behaviour, since every method of the
+    /// array relies on this layout, and `get_unchecked` and `get_unchecked_mut`
+    /// skip their bounds checks because of it
+    pub unsafe fn from_raw_parts(backing: Vec<VecDeque<T>>, row_capacity: usize) -> Igush<T> {
+        let length: usize = backing.iter().map(VecDeque::len).sum();
+
+        Igush {
+            capacity: backing.capacity() * row_capacity,
+            // counted from the rows themselves, so the rows in use never run
+            // past the backing even if the last row is too long
+            rows: Self::rows_holding_elements(&backing),
+            backing,
+            row_capacity,
+            length,
+            adaptive: false,
+        }
+    }
+
+    /// assemble an array from its rows, as returned by `into_parts`
+    ///
+    /// every row holding elements must be full except the last one holding elements,
+    /// which must not hold more than `row_capacity` elements, and every row after
+    /// that must be empty, otherwise this returns an error
+    pub fn from_parts(
+        backing: Vec<VecDeque<T>>,
+        row_capacity: usize,
+    ) -> Result<Igush<T>, InvalidLayout> {
+        if row_capacity == 0 {
+            return Err(InvalidLayout);
+        }
+
+        let rows = Self::rows_holding_elements(&backing);
+        if let Some((last, full)) = backing[..rows].split_last() {
+            if last.len() > row_capacity || full.iter().any(|row| row.len() != row_capacity) {
+                return Err(InvalidLayout);
+            }
+        }
+
+        // SAFETY: the layout was checked above
+        Ok(unsafe { Self::from_raw_parts(backing, row_capacity) })
+    }
+
+    /// number of rows up to and including the last one holding elements
+    fn rows_holding_elements(backing: &[VecDeque<T>]) -> usize {
+        backing
+            .iter()
+            .rposition(|row| !row.is_empty())
+            .map_or(0, |i| i + 1)
+    }
+
+    /// split the array into its rows and its row width
+    ///
+    /// every row but the last one holding elements is full,
+    /// and any rows after that are empty
+    pub fn into_parts(self) -> (Vec<VecDeque<T>>, usize) {
+        (self.backing, self.row_capacity)
+    }
+
+    /// create a new array with room for at least `capacity` elements,
+    /// choosing a row width of about `sqrt(capacity)`
+    ///
+    /// ```
+    /// use igush_rs::Igush;
+    ///
+    /// let array: Igush<u8> = Igush::with_capacity(10_000);
+    /// assert_eq!(array.row_capacity(), 100);
+    /// assert!(array.capacity() >= 10_000);
+    /// ```
+    pub fn with_capacity(capacity: usize) -> Igush<T> {
+        Self::with_row_capacity(auto_row_capacity(capacity), capacity)
+    }
+
+    /// collect the elements of an iterator into a new array with the given row width
+    ///
+    /// panics if `row_capacity` is zero
+    pub fn from_iter_with_row_capacity<I: IntoIterator<Item = T>>(
+        row_capacity: usize,
+        iter: I,
+    ) -> Igush<T> {
+        let iter = iter.into_iter();
+        let (lower, _) = iter.size_hint();
+
+        let mut array = Igush::with_row_capacity(row_capacity, lower);
+        for element in iter {
+            array.push_back(element);
+        }
+
+        array
+    }
+
+    /// create a new array holding `n` clones of `value` with the given row width
+    ///
+    /// panics if `row_capacity` is zero
+    pub fn from_elem(value: T, n: usize, row_capacity: usize) -> Igush<T>
+    where
+        T: Clone,
+    {
+        Self::from_iter_with_row_capacity(row_capacity, core::iter::repeat_n(value, n))
+    }
+
+    /// join the given arrays into one, in order
+    ///
+    /// the row capacity is chosen from the total length
+    pub fn concat(arrays: Vec<Igush<T>>) -> Igush<T> {
+        let length = arrays.iter().map(Igush::len).sum();
+
+        let mut array = Igush::with_row_capacity(auto_row_capacity(length), length);
+        for other in arrays {
+            array.extend_back(other);
         }
+
+        array
     }
-    /// create a new array with a default row width of 10
+
+    /// create a new array with the default row width of `DEFAULT_ROW_CAPACITY`
     pub fn new() -> Igush<T> {
-        Self::with_row_capacity(10, 0)
+        Self::with_row_capacity(DEFAULT_ROW_CAPACITY, 0)
     }
 
-    /// add a new DEQ if an insertion will overflow the current end DEQ
+    /// start using a new DEQ if an insertion will overflow the current end DEQ
     fn grow_if_necessary(&mut self) {
-        if self.rows == 0 {
+        // nothing to do unless there are no DEQs in use or the end DEQ is full
+        if self.rows > 0 && self.backing[self.rows - 1].len() < self.row_capacity {
+            return;
+        }
+
+        // only allocate a new DEQ if there are no available empty ones
+        if self.rows == self.backing.len() {
             let end = VecDeque::with_capacity(self.row_capacity);
             self.backing.push(end);
-            self.rows = self.backing.len();
 
             self.capacity = self.backing.capacity() * self.row_capacity;
         }
 
-        // exit early if there are available empty DEQs
-        if self.rows < self.backing.len() {
-            return;
+        self.rows += 1;
+    }
+
+    /// widen the rows of an adaptive array once they fall well behind `sqrt(N)`
+    fn adapt_if_necessary(&mut self) {
+        // rebuilding once the length reaches four times the square of the width
+        // doubles the width, so the cost is amortized like `Vec` growth
+        if self.adaptive && self.length / self.row_capacity / 4 >= self.row_capacity {
+            self.set_row_capacity(self.length.isqrt());
         }
+    }
 
-        // check if end DEQ is full
-        if self.backing[self.rows - 1].len() == self.row_capacity {
-            let end = VecDeque::with_capacity(self.row_capacity);
-            self.backing.push(end);
-            self.rows = self.backing.len();
+    /// stop using the end DEQ if a removal left it empty
+    fn shrink_if_necessary(&mut self) {
+        if self.rows > 0 && self.backing[self.rows - 1].is_empty() {
+            self.rows -= 1;
+        }
+    }
 
-            self.capacity = self.backing.capacity() * self.row_capacity;
+    /// resolve a range of indices into the array, panicking if it is out of bounds
+    fn range_bounds<R: RangeBounds<usize>>(&self, range: R) -> (usize, usize) {
+        let start = match range.start_bound() {
+            Bound::Included(&start) => start,
+            Bound::Excluded(&start) => start.checked_add(1).expect("range start out of bounds"),
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&end) => end.checked_add(1).expect("range end out of bounds"),
+            Bound::Excluded(&end) => end,
+            Bound::Unbounded => self.len(),
+        };
+
+        assert!(start <= end, "range start is greater than range end");
+        assert!(end <= self.len(), "range end out of bounds");
+
+        (start, end)
+    }
+
+    /// move every element into a `Vec`, let `f` operate on them, then refill the rows in order
+    ///
+    /// if `f` panics, whatever it left in the `Vec` is still put back into the rows
+    fn rebuild_with<R>(&mut self, f: impl FnOnce(&mut Vec<T>) -> R) -> R {
+        /// refills the rows from the elements, even if `f` panics
+        struct Guard<'a, T> {
+            array: &'a mut Igush<T>,
+            elements: Vec<T>,
+        }
+
+        impl<T> Drop for Guard<'_, T> {
+            fn drop(&mut self) {
+                for element in self.elements.drain(..) {
+                    self.array.push_back(element);
+                }
+            }
+        }
+
+        let mut elements = Vec::with_capacity(self.length);
+        for row in &mut self.backing[..self.rows] {
+            elements.extend(row.drain(..));
+        }
+        self.rows = 0;
+        self.length = 0;
+
+        let mut guard = Guard {
+            array: self,
+            elements,
+        };
+        f(&mut guard.elements)
+    }
+
+    /// remove and return the first `n` elements, moving whole rows where possible
+    fn take_front(&mut self, n: usize) -> Vec<T> {
+        debug_assert!(n <= self.length);
+
+        let mut taken = Vec::with_capacity(n);
+        let whole_rows = n / self.row_capacity;
+        let rest = n % self.row_capacity;
+
+        // empty the leading rows and move them behind the rows in use
+        for row in &mut self.backing[..whole_rows] {
+            taken.extend(row.drain(..));
+        }
+        self.backing[..self.rows].rotate_left(whole_rows);
+        self.rows -= whole_rows;
+
+        // take the rest from the first row, then shift each row forward to refill it
+        if rest > 0 {
+            taken.extend(self.backing[0].drain(..rest));
+
+            for row in 1..self.rows {
+                for _ in 0..rest.min(self.backing[row].len()) {
+                    let front = self.backing[row].pop_front().unwrap();
+                    self.backing[row - 1].push_back(front);
+                }
+            }
+            self.shrink_if_necessary();
         }
+
+        self.length -= n;
+        taken
     }
 
     /// number of elements stored in the array
@@ -84,10 +380,144 @@ impl<T> Igush<T> {
     }
 
     /// returns the number of elements the array can hold without reallocating
+    ///
+    /// this is always `rows_capacity() * row_capacity()`, since each row
+    /// is allocated with room for a full row; see `memory_usage` for bytes
     pub fn capacity(&self) -> usize {
         self.capacity
     }
 
+    /// returns the number of elements each row holds
+    ///
+    /// ```
+    /// use igush_rs::Igush;
+    ///
+    /// // converting a `Vec` picks a row capacity of about `sqrt(len)`
+    /// let array = Igush::from(vec![0; 100]);
+    /// assert_eq!(array.row_capacity(), 10);
+    ///
+    /// let array: Igush<i32> = Igush::with_row_capacity(4, 0);
+    /// assert_eq!(array.row_capacity(), 4);
+    /// ```
+    pub fn row_capacity(&self) -> usize {
+        self.row_capacity
+    }
+
+    /// returns the number of rows currently holding elements
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// returns the number of rows the backing can hold without reallocating
+    pub fn rows_capacity(&self) -> usize {
+        self.backing.capacity()
+    }
+
+    /// returns both the number of elements and the number of rows
+    /// the array can hold without reallocating its backing,
+    /// the same as `(capacity(), rows_capacity())`
+    ///
+    /// ```
+    /// use igush_rs::Igush;
+    ///
+    /// let array: Igush<u32> = Igush::with_row_capacity(10, 1000);
+    /// assert_eq!(array.total_capacity(), (1000, 100));
+    /// ```
+    pub fn total_capacity(&self) -> (usize, usize) {
+        (self.capacity(), self.rows_capacity())
+    }
+
+    /// estimate the number of heap bytes used by the backing and its rows
+    pub fn memory_usage(&self) -> usize {
+        let rows = self.backing.capacity() * mem::size_of::<VecDeque<T>>();
+        let elements: usize = self.backing.iter().map(VecDeque::capacity).sum();
+
+        rows + elements * mem::size_of::<T>()
+    }
+
+    /// reserve capacity for at least `additional` more elements
+    ///
+    /// panics if the allocation fails
+    pub fn reserve(&mut self, additional: usize) {
+        if let Err(error) = self.try_reserve(additional) {
+            panic!("{}", error);
+        }
+    }
+
+    /// try to reserve capacity for at least `additional` more elements
+    ///
+    /// returns an error instead of panicking if the allocation fails
+    pub fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        self.try_reserve_elements(additional, false)
+    }
+
+    /// try to reserve capacity for at least `additional` more elements,
+    /// without deliberately over-allocating the list of DEQs
+    ///
+    /// returns an error instead of panicking if the allocation fails
+    pub fn try_reserve_exact(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        self.try_reserve_elements(additional, true)
+    }
+
+    /// reserve enough rows for at least `additional` more full rows of elements
+    ///
+    /// panics if the allocation fails
+    pub fn reserve_rows(&mut self, additional: usize) {
+        self.reserve(additional.saturating_mul(self.row_capacity));
+    }
+
+    /// allocate enough empty DEQs to hold `additional` more elements
+    fn try_reserve_elements(
+        &mut self,
+        additional: usize,
+        exact: bool,
+    ) -> Result<(), TryReserveError> {
+        let rows = match self.length.checked_add(additional) {
+            Some(total) => total.div_ceil(self.row_capacity),
+            // no allocation can be this large, let `Vec` report the overflow
+            None => return Vec::<u8>::new().try_reserve(usize::MAX),
+        };
+
+        if rows <= self.backing.len() {
+            return Ok(());
+        }
+
+        let new_rows = rows - self.backing.len();
+        if exact {
+            self.backing.try_reserve_exact(new_rows)?;
+        } else {
+            self.backing.try_reserve(new_rows)?;
+        }
+        self.capacity = self.backing.capacity() * self.row_capacity;
+
+        for _ in 0..new_rows {
+            let mut row = VecDeque::new();
+            row.try_reserve_exact(self.row_capacity)?;
+            self.backing.push(row);
+        }
+
+        Ok(())
+    }
+
+    /// shrink the capacity of the array as close to `min_capacity` as possible,
+    /// without dropping below its length
+    ///
+    /// unused DEQs beyond what that capacity needs are released
+    pub fn shrink_to(&mut self, min_capacity: usize) {
+        let rows = self.length.max(min_capacity).div_ceil(self.row_capacity);
+
+        self.backing.truncate(rows);
+        self.backing.shrink_to(rows);
+
+        self.capacity = self.backing.capacity() * self.row_capacity;
+    }
+
+    /// shrink the capacity of the array as close to its length as possible,
+    /// releasing every unused DEQ
+    pub fn shrink_to_fit(&mut self) {
+        self.shrink_to(0);
+    }
+
     /// insert an element at the end of the array
     pub fn push_back(&mut self, element: T) {
         self.grow_if_necessary();
@@ -95,6 +525,45 @@ impl<T> Igush<T> {
         self.backing[self.rows - 1].push_back(element);
 
         self.length += 1;
+        self.adapt_if_necessary();
+    }
+
+    /// insert every element of an iterator at the end of the array, in order
+    pub fn extend_back<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+        let (lower, _) = iter.size_hint();
+
+        self.reserve(lower);
+        for element in iter {
+            self.push_back(element);
+        }
+    }
+
+    /// clone and append all elements of a slice to the end of the array
+    ///
+    /// this fills each row in turn, rather than pushing one element at a time
+    pub fn extend_from_slice(&mut self, other: &[T])
+    where
+        T: Clone,
+    {
+        self.reserve(other.len());
+
+        let mut rest = other;
+        while !rest.is_empty() {
+            self.grow_if_necessary();
+
+            let row = &mut self.backing[self.rows - 1];
+            let room = self.row_capacity - row.len();
+            let (chunk, tail) = rest.split_at(room.min(rest.len()));
+            for element in chunk {
+                row.push_back(element.clone());
+                self.length += 1;
+            }
+
+            rest = tail;
+        }
+
+        self.adapt_if_necessary();
     }
 
     /// insert an element at the beginning of the array
@@ -108,9 +577,63 @@ impl<T> Igush<T> {
         self.backing[0].push_front(element);
 
         self.length += 1;
+        self.adapt_if_necessary();
+    }
+
+    /// insert every element of an iterator at the beginning of the array,
+    /// keeping the order of the iterator
+    ///
+    /// rather than pushing each element to the front, whole rows of new
+    /// elements are placed before the existing rows, and only the leftover
+    /// elements that do not fill a row are passed along the rows, once
+    pub fn extend_front<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let mut new: Vec<T> = iter.into_iter().collect();
+        let count = new.len();
+        if count == 0 {
+            return;
+        }
+
+        // the leftover elements shift every existing row, pushing the same
+        // number of elements off the back of each row into the next one
+        let mut carry: VecDeque<T> = new.split_off(count - count % self.row_capacity).into();
+        for row in &mut self.backing[..self.rows] {
+            while let Some(element) = carry.pop_back() {
+                row.push_front(element);
+            }
+            while row.len() > self.row_capacity {
+                carry.push_front(row.pop_back().unwrap());
+            }
+        }
+        if !carry.is_empty() {
+            if self.rows == self.backing.len() {
+                self.backing
+                    .push(VecDeque::with_capacity(self.row_capacity));
+            }
+            self.backing[self.rows].append(&mut carry);
+            self.rows += 1;
+        }
+
+        let mut full_rows = Vec::with_capacity(new.len() / self.row_capacity);
+        let mut elements = new.into_iter();
+        while elements.len() > 0 {
+            full_rows.push(elements.by_ref().take(self.row_capacity).collect());
+        }
+        self.rows += full_rows.len();
+        self.backing.splice(0..0, full_rows);
+
+        self.length += count;
+        self.capacity = self.backing.capacity() * self.row_capacity;
+        self.adapt_if_necessary();
     }
 
     /// insert an element at an arbitrary position
+    ///
+    /// each row after the target passes its last element back to make room,
+    /// and then the DEQ shifts whichever side of the position is shorter,
+    /// so this is `O(row_capacity + rows)`; see `Igush` for why earlier rows
+    /// are never shifted instead
+    ///
+    /// panics if `index` is greater than the length of the array
     pub fn insert(&mut self, index: usize, element: T) {
         assert!(index <= self.len(), "index out of bounds");
         self.grow_if_necessary();
@@ -119,7 +642,7 @@ impl<T> Igush<T> {
         let column = index % self.row_capacity;
 
         if self.backing[target_row].len() == self.row_capacity {
-            for row in (target_row + 1)..self.rows {
+            for row in ((target_row + 1)..self.rows).rev() {
                 let back = self.backing[row - 1].pop_back().unwrap();
                 self.backing[row].push_front(back);
             }
@@ -129,6 +652,43 @@ impl<T> Igush<T> {
         self.length += 1;
     }
 
+    /// insert an element at the given index, handing the index and element back
+    /// instead of panicking if the index is out of bounds
+    pub fn try_insert(&mut self, index: usize, element: T) -> Result<(), (usize, T)> {
+        if index > self.len() {
+            return Err((index, element));
+        }
+
+        self.insert(index, element);
+        Ok(())
+    }
+
+    /// insert every element of an iterator starting at the given position, in order
+    ///
+    /// panics if `index` is greater than the length of the array
+    pub fn insert_many<I: IntoIterator<Item = T>>(&mut self, index: usize, iter: I) {
+        assert!(index <= self.len(), "index out of bounds");
+
+        let mut tail = self.split_off(index);
+        self.extend_back(iter);
+        self.append(&mut tail);
+    }
+
+    /// insert an element into a sorted array, keeping it sorted
+    ///
+    /// returns the index the element was inserted at
+    pub fn insert_sorted(&mut self, element: T) -> usize
+    where
+        T: Ord,
+    {
+        let index = match self.binary_search(&element) {
+            Ok(index) | Err(index) => index,
+        };
+        self.insert(index, element);
+
+        index
+    }
+
     /// remove and return the element at the end of the array
     pub fn pop_back(&mut self) -> Option<T> {
         if self.is_empty() {
@@ -140,6 +700,7 @@ impl<T> Igush<T> {
         if element.is_some() {
             self.length -= 1;
         }
+        self.shrink_if_necessary();
 
         element
     }
@@ -160,11 +721,17 @@ impl<T> Igush<T> {
         if element.is_some() {
             self.length -= 1;
         }
+        self.shrink_if_necessary();
 
         element
     }
 
     /// remove and return an element in the array by index
+    ///
+    /// within its row, the DEQ shifts whichever side of the element is shorter,
+    /// and then each later row passes one element forward to refill the gap,
+    /// so this is `O(row_capacity + rows)`; see `Igush` for why earlier rows
+    /// are never shifted instead
     pub fn remove(&mut self, index: usize) -> Option<T> {
         if index >= self.len() {
             return None;
@@ -183,220 +750,4497 @@ impl<T> Igush<T> {
         if element.is_some() {
             self.length -= 1;
         }
+        self.shrink_if_necessary();
 
         element
     }
 
-    /// retrieves an element in the array mutably by index
-    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
-        let target_row = index / self.row_capacity;
-        let column = index % self.row_capacity;
+    /// remove and return an element in the array by index, replacing it with the last element
+    ///
+    /// this does not preserve the order of the array, but avoids shifting any other elements
+    pub fn swap_remove(&mut self, index: usize) -> Option<T> {
+        if index >= self.len() {
+            return None;
+        }
 
-        self.backing
-            .get_mut(target_row)
-            .and_then(|x: &mut VecDeque<T>| x.get_mut(column))
+        let last = self.pop_back().unwrap();
+        if index == self.len() {
+            return Some(last);
+        }
+
+        self.get_mut(index)
+            .map(|element| mem::replace(element, last))
     }
 
-    /// retrieves an element in the array by index
-    pub fn get(&mut self, index: usize) -> Option<&T> {
-        let target_row = index / self.row_capacity;
-        let column = index % self.row_capacity;
+    /// replace the given range of elements with the elements of an iterator,
+    /// returning the removed elements in an iterator
+    ///
+    /// the replacement happens immediately, even if the returned iterator
+    /// is not consumed
+    ///
+    /// panics if the range is out of bounds
+    pub fn splice<R, I>(&mut self, range: R, replace_with: I) -> Drain<T>
+    where
+        R: RangeBounds<usize>,
+        I: IntoIterator<Item = T>,
+    {
+        let (start, end) = self.range_bounds(range);
 
-        self.backing
-            .get(target_row)
-            .and_then(|x: &VecDeque<T>| x.get(column))
-    }
-}
+        let removed = self.drain(start..end);
+        self.insert_many(start, replace_with);
 
-impl<T: PartialEq> PartialEq for Igush<T> {
-    fn eq(&self, other: &Self) -> bool {
-        self.backing.eq(&other.backing)
+        removed
     }
-}
-impl<T: Eq> Eq for Igush<T> {}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    /// move all elements of `other` to the end of the array, leaving `other` empty
+    pub fn append(&mut self, other: &mut Self) {
+        if self.length.is_multiple_of(self.row_capacity) && self.row_capacity == other.row_capacity
+        {
+            // every row in use is full, so the rows of `other` can be moved over whole
+            let moved = other.backing.drain(..other.rows);
+            self.backing.splice(self.rows..self.rows, moved);
+            self.rows += other.rows;
+            self.length += other.length;
 
-    #[test]
-    fn create() {
-        let created: Igush<i32> = Igush::with_row_capacity(32, 0);
+            self.capacity = self.backing.capacity() * self.row_capacity;
+        } else {
+            for row in &mut other.backing[..other.rows] {
+                for element in row.drain(..) {
+                    self.push_back(element);
+                }
+            }
+        }
 
-        assert_eq!(created.capacity(), 0);
-        assert_eq!(created.len(), 0);
+        other.rows = 0;
+        other.length = 0;
+    }
 
-        let created: Igush<i32> = Igush::with_row_capacity(5, 5);
+    /// move all elements of `other` to the end of the array, consuming `other`
+    ///
+    /// like `append`, whole rows are moved over when the row widths match
+    /// and every row in use is full
+    pub fn absorb(&mut self, mut other: Self) {
+        self.append(&mut other);
+    }
 
-        assert_eq!(created.capacity(), 5);
-        assert_eq!(created.len(), 0);
+    /// swap the contents of this array with those of `other`, in constant time
+    ///
+    /// the row widths, capacities and adaptive settings are swapped as well,
+    /// and the arrays may have different lengths
+    pub fn swap_contents(&mut self, other: &mut Self) {
+        mem::swap(self, other);
+    }
 
-        let created: Igush<i32> = Igush::with_row_capacity(10, 11);
+    /// remove the given range of elements, returning them in an iterator
+    ///
+    /// the elements are removed even if the iterator is not fully consumed
+    ///
+    /// panics if the range is out of bounds
+    pub fn drain<R: RangeBounds<usize>>(&mut self, range: R) -> Drain<T> {
+        let (start, end) = self.range_bounds(range);
 
-        assert_eq!(created.capacity(), 20);
-        assert_eq!(created.len(), 0);
+        // popping from the back is cheap, so take everything after `start`
+        // off the back and put the elements after the range back afterwards
+        let mut tail = Vec::with_capacity(self.len() - start);
+        while self.len() > start {
+            tail.push(self.pop_back().unwrap());
+        }
+
+        let drained = tail.split_off(tail.len() - (end - start));
+        for element in tail.into_iter().rev() {
+            self.push_back(element);
+        }
+
+        Drain::new(drained.into_iter().rev().collect())
     }
 
-    #[test]
-    fn push_back() {
-        let mut array: Igush<i32> = Igush::with_row_capacity(5, 0);
+    /// remove every element, returning them in ascending order
+    ///
+    /// the array is emptied immediately, even if the returned iterator
+    /// is not consumed
+    pub fn drain_sorted(&mut self) -> Drain<T>
+    where
+        T: Ord,
+    {
+        let mut elements = self.rebuild_with(mem::take);
+        elements.sort();
 
-        for _ in 0..20 {
-            array.push_back(1);
-        }
+        Drain::new(elements)
+    }
 
-        assert_eq!(array.len(), 20);
+    /// keep only the elements matching the predicate, preserving their order
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.retain_mut(|element| f(element));
     }
 
-    #[test]
-    fn push_front() {
-        let mut array: Igush<i32> = Igush::with_row_capacity(5, 0);
+    /// keep only the elements matching the predicate, preserving their order
+    ///
+    /// the predicate may modify the elements as it visits them
+    ///
+    /// the kept elements are compacted in place in a single pass however many
+    /// are removed, so elements before the first removed one are never moved,
+    /// and removed elements are popped off the back
+    ///
+    /// if the predicate panics, the elements it rejected so far are removed
+    /// and the rest are kept, in their original order
+    pub fn retain_mut<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&mut T) -> bool,
+    {
+        /// removes the rejected elements, even if the predicate panics
+        struct Guard<'a, T> {
+            array: &'a mut Igush<T>,
+            /// number of elements the predicate has been called on
+            processed: usize,
+            /// number of elements kept, compacted at the front
+            kept: usize,
+        }
 
-        for i in 0..20 {
-            array.push_front(i);
+        impl<T> Drop for Guard<'_, T> {
+            fn drop(&mut self) {
+                if self.processed == self.array.len() {
+                    self.array.truncate(self.kept);
+                } else {
+                    self.array.drain(self.kept..self.processed);
+                }
+            }
         }
 
-        assert_eq!(array.len(), 20);
+        let mut guard = Guard {
+            array: self,
+            processed: 0,
+            kept: 0,
+        };
+        while guard.processed < guard.array.len() {
+            let index = guard.processed;
+            if f(guard.array.get_mut(index).unwrap()) {
+                if guard.kept != index {
+                    guard.array.swap(guard.kept, index);
+                }
+                guard.kept += 1;
+            }
+            guard.processed += 1;
+        }
     }
 
-    #[test]
-    fn get() {
-        let mut array: Igush<i32> = Igush::with_row_capacity(5, 0);
+    /// keep only the elements matching the predicate, preserving their order,
+    /// and return the number of elements removed
+    pub fn retain_count<F>(&mut self, f: F) -> usize
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let length = self.len();
+        self.retain(f);
 
-        assert_eq!(array.get(0), None);
+        length - self.len()
+    }
 
-        array.push_back(1);
+    /// remove every element matching the predicate, returning them in an iterator
+    ///
+    /// the remaining elements keep their order, and the matching elements
+    /// are removed even if the iterator is not fully consumed
+    pub fn extract_if<F>(&mut self, pred: F) -> Drain<T>
+    where
+        F: FnMut(&mut T) -> bool,
+    {
+        self.rebuild_with(|elements| Drain::new(elements.extract_if(.., pred).collect()))
+    }
 
-        assert_eq!(array.get(0), Some(&1));
+    /// sort the array, keeping equal elements in their original order
+    pub fn sort(&mut self)
+    where
+        T: Ord,
+    {
+        self.rebuild_with(|elements| elements.sort());
     }
 
-    #[test]
-    fn get_mut() {
-        let mut array: Igush<i32> = Igush::with_row_capacity(5, 0);
+    /// sort the array with a comparator function,
+    /// keeping equal elements in their original order
+    pub fn sort_by<F>(&mut self, compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        self.rebuild_with(|elements| elements.sort_by(compare));
+    }
 
-        assert_eq!(array.get_mut(0), None);
+    /// sort the array by the given key extraction function,
+    /// keeping elements with equal keys in their original order
+    pub fn sort_by_key<K, F>(&mut self, key: F)
+    where
+        F: FnMut(&T) -> K,
+        K: Ord,
+    {
+        self.rebuild_with(|elements| elements.sort_by_key(key));
+    }
 
-        array.push_back(1);
+    /// remove up to `n` elements from the beginning of the array,
+    /// returning them in order
+    pub fn drain_front(&mut self, n: usize) -> Drain<T> {
+        let n = n.min(self.len());
 
-        assert_eq!(array.get_mut(0), Some(&mut 1));
+        Drain::new(self.take_front(n))
+    }
 
-        let x = array.get_mut(0).unwrap();
-        *x = 5;
+    /// remove up to `n` elements from the end of the array,
+    /// returning them in order
+    pub fn drain_back(&mut self, n: usize) -> Drain<T> {
+        let n = n.min(self.len());
 
-        assert_eq!(array.get_mut(0), Some(&mut 5));
+        Drain::new(self.split_off(self.len() - n).into())
     }
 
-    #[test]
-    fn insert() {
-        let mut array: Igush<i32> = Igush::with_row_capacity(5, 0);
+    /// shorten the array to the first `len` elements, dropping the rest
+    ///
+    /// does nothing if the array is already no longer than `len`
+    pub fn truncate(&mut self, len: usize) {
+        while self.len() > len {
+            self.pop_back();
+        }
+    }
 
-        array.push_back(0);
-        array.push_back(1);
-        array.push_back(2);
-        array.push_back(3);
+    /// shorten the array to the first `len` elements, returning the rest
+    /// as a new array with the same row width
+    ///
+    /// returns an empty array if this one is already no longer than `len`
+    pub fn split_truncate(&mut self, len: usize) -> Self {
+        if len < self.len() {
+            self.split_off(len)
+        } else {
+            Igush::with_row_capacity(self.row_capacity, 0)
+        }
+    }
+
+    /// shorten the array to the last `len` elements, dropping the rest
+    ///
+    /// does nothing if the array is already no longer than `len`
+    pub fn truncate_front(&mut self, len: usize) {
+        if len < self.len() {
+            self.take_front(self.len() - len);
+        }
+    }
+
+    /// remove every element, keeping the allocated capacity
+    pub fn clear(&mut self) {
+        for row in &mut self.backing[..self.rows] {
+            row.clear();
+        }
+
+        self.rows = 0;
+        self.length = 0;
+    }
+
+    /// remove every element and release all allocated capacity
+    pub fn clear_and_shrink(&mut self) {
+        self.clear();
+        self.shrink_to_fit();
+    }
+
+    /// split the array in two at the given index
+    ///
+    /// returns a new array holding the elements from `at` onward,
+    /// leaving the elements before `at` in this one
+    ///
+    /// panics if `at` is greater than the length of the array
+    pub fn split_off(&mut self, at: usize) -> Self {
+        assert!(at <= self.len(), "at out of bounds");
+
+        let mut other = Igush::with_row_capacity(self.row_capacity, 0);
+        other.adaptive = self.adaptive;
+
+        if at.is_multiple_of(self.row_capacity) {
+            // the split falls between rows, so the tail rows can be moved over whole
+            let first_row = at / self.row_capacity;
+            other.backing = self.backing.drain(first_row..self.rows).collect();
+            other.capacity = other.backing.capacity() * other.row_capacity;
+            other.rows = other.backing.len();
+            other.length = self.length - at;
+
+            self.rows = first_row;
+            self.length = at;
+        } else {
+            // only the tail has to move, and popping it off the back is cheap
+            let mut tail = Vec::with_capacity(self.length - at);
+            while self.len() > at {
+                tail.push(self.pop_back().unwrap());
+            }
+
+            for element in tail.into_iter().rev() {
+                other.push_back(element);
+            }
+        }
+
+        other
+    }
+
+    /// split the array in two at the given index
+    ///
+    /// returns a new array holding the elements before `at`,
+    /// leaving the elements from `at` onward in this one
+    ///
+    /// panics if `at` is greater than the length of the array
+    pub fn split_off_front(&mut self, at: usize) -> Self {
+        assert!(at <= self.len(), "at out of bounds");
+
+        // move whichever side of the split is shorter
+        if at <= self.len() - at {
+            let mut front = Igush::with_row_capacity(self.row_capacity, at);
+            front.adaptive = self.adaptive;
+            front.extend_back(self.take_front(at));
+
+            front
+        } else {
+            let back = self.split_off(at);
+
+            mem::replace(self, back)
+        }
+    }
+
+    /// remove consecutive repeated elements, keeping the first of each run
+    pub fn dedup(&mut self)
+    where
+        T: PartialEq,
+    {
+        self.rebuild_with(|elements| elements.dedup());
+    }
+
+    /// remove consecutive repeated elements, keeping the first of each run,
+    /// and return each run's element along with the length of the run
+    ///
+    /// this is a single in-place pass, like `retain_mut`, and if `PartialEq`
+    /// panics the repeats found so far are removed and the rest are kept
+    pub fn dedup_with_count(&mut self) -> Vec<(T, usize)>
+    where
+        T: PartialEq + Clone,
+    {
+        let mut runs: Vec<(T, usize)> = Vec::new();
+        self.retain_mut(|element| match runs.last_mut() {
+            Some((value, count)) if *element == *value => {
+                *count += 1;
+                false
+            }
+            _ => {
+                runs.push((element.clone(), 1));
+                true
+            }
+        });
+
+        runs
+    }
+
+    /// remove consecutive elements the given function considers equal,
+    /// keeping the first of each run
+    ///
+    /// the function is passed the later element first, like `Vec::dedup_by`
+    pub fn dedup_by<F>(&mut self, same_bucket: F)
+    where
+        F: FnMut(&mut T, &mut T) -> bool,
+    {
+        self.rebuild_with(|elements| elements.dedup_by(same_bucket));
+    }
+
+    /// remove consecutive elements that map to the same key, keeping the first of each run
+    pub fn dedup_by_key<K, F>(&mut self, key: F)
+    where
+        F: FnMut(&mut T) -> K,
+        K: PartialEq,
+    {
+        self.rebuild_with(|elements| elements.dedup_by_key(key));
+    }
+
+    /// move the first `mid` elements to the end of the array, preserving order
+    ///
+    /// when `mid` is a multiple of the row capacity the rows are rotated whole,
+    /// but if that leaves the partial end row in the middle, every row after it
+    /// passes elements forward to refill it, so this is still `O(N)` at worst;
+    /// otherwise the shorter side is moved an element at a time
+    ///
+    /// panics if `mid` is greater than the length of the array
+    pub fn rotate_left(&mut self, mid: usize) {
+        assert!(mid <= self.len(), "mid out of bounds");
+
+        if mid == 0 || mid == self.len() {
+            return;
+        }
+
+        if mid.is_multiple_of(self.row_capacity) {
+            // the split falls between rows, so the rows can be rotated whole
+            let whole_rows = mid / self.row_capacity;
+            self.backing[..self.rows].rotate_left(whole_rows);
+
+            // the end row may now sit in the middle, so refill it from the rows after it
+            let end = self.rows - whole_rows - 1;
+            let missing = self.row_capacity - self.backing[end].len();
+            for row in (end + 1)..self.rows {
+                for _ in 0..missing {
+                    let front = self.backing[row].pop_front().unwrap();
+                    self.backing[row - 1].push_back(front);
+                }
+            }
+
+            return;
+        }
+
+        // move whichever side of the split is shorter
+        let k = self.len() - mid;
+        if mid <= k {
+            for _ in 0..mid {
+                let front = self.pop_front().unwrap();
+                self.push_back(front);
+            }
+        } else {
+            for _ in 0..k {
+                let back = self.pop_back().unwrap();
+                self.push_front(back);
+            }
+        }
+    }
+
+    /// move the last `k` elements to the beginning of the array, preserving order
+    ///
+    /// panics if `k` is greater than the length of the array
+    pub fn rotate_right(&mut self, k: usize) {
+        assert!(k <= self.len(), "k out of bounds");
+
+        self.rotate_left(self.len() - k);
+    }
+
+    /// rotate the array `k` places to the left, wrapping `k` around the length
+    ///
+    /// unlike `rotate_left`, this doesn't panic if `k` is greater than the length,
+    /// so rotating ten elements by thirteen is the same as rotating them by three
+    pub fn rotate_left_wrapping(&mut self, k: usize) {
+        if !self.is_empty() {
+            self.rotate_left(k % self.len());
+        }
+    }
+
+    /// rotate the array `k` places to the right, wrapping `k` around the length
+    ///
+    /// unlike `rotate_right`, this doesn't panic if `k` is greater than the length
+    pub fn rotate_right_wrapping(&mut self, k: usize) {
+        if !self.is_empty() {
+            self.rotate_right(k % self.len());
+        }
+    }
+
+    /// retrieves an element in the array mutably by index
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        let target_row = index / self.row_capacity;
+        let column = index % self.row_capacity;
+
+        self.backing
+            .get_mut(target_row)
+            .and_then(|x: &mut VecDeque<T>| x.get_mut(column))
+    }
+
+    /// retrieves two distinct elements in the array mutably by index
+    ///
+    /// returns `None` if the indices are equal or either is out of bounds
+    pub fn get_mut_pair(&mut self, i: usize, j: usize) -> Option<(&mut T, &mut T)> {
+        if i == j || i >= self.len() || j >= self.len() {
+            return None;
+        }
+
+        let (low, high) = (i.min(j), i.max(j));
+        let (low_row, low_column) = (low / self.row_capacity, low % self.row_capacity);
+        let (high_row, high_column) = (high / self.row_capacity, high % self.row_capacity);
+
+        let (low, high) = if low_row == high_row {
+            // the ends of a range within one DEQ are disjoint
+            let mut range = self.backing[low_row].range_mut(low_column..=high_column);
+            (range.next()?, range.next_back()?)
+        } else {
+            let (head, tail) = self.backing.split_at_mut(high_row);
+            (
+                head[low_row].get_mut(low_column)?,
+                tail[0].get_mut(high_column)?,
+            )
+        };
+
+        if i < j {
+            Some((low, high))
+        } else {
+            Some((high, low))
+        }
+    }
+
+    /// returns mutable references to the elements at each of the given indices
+    ///
+    /// returns `None` if any index is out of bounds or appears more than once
+    pub fn get_disjoint_mut<const N: usize>(&mut self, indices: [usize; N]) -> Option<[&mut T; N]> {
+        // visit the indices in ascending order, so each row is walked only once
+        let mut order: [usize; N] = core::array::from_fn(|k| k);
+        order.sort_unstable_by_key(|&k| indices[k]);
+
+        // a repeated index ends up next to its duplicate
+        if order
+            .windows(2)
+            .any(|pair| indices[pair[0]] == indices[pair[1]])
+        {
+            return None;
+        }
+        if order.last().is_some_and(|&k| indices[k] >= self.len()) {
+            return None;
+        }
+
+        let row_capacity = self.row_capacity;
+        let mut found: [Option<&mut T>; N] = core::array::from_fn(|_| None);
+        let mut rows = self.backing.iter_mut();
+        // index of the row that `rows` will yield next
+        let mut next_row = 0;
+        // the row being walked, with the column that it will yield next
+        let mut current = None;
+
+        for k in order {
+            let (row, column) = (indices[k] / row_capacity, indices[k] % row_capacity);
+
+            if row >= next_row {
+                current = Some((rows.nth(row - next_row)?.iter_mut(), 0));
+                next_row = row + 1;
+            }
+
+            let (elements, next_column) = current.as_mut()?;
+            found[k] = elements.nth(column - *next_column);
+            *next_column = column + 1;
+        }
+
+        Some(found.map(Option::unwrap))
+    }
+
+    /// swap two elements in the array by index
+    ///
+    /// panics if either index is out of bounds
+    pub fn swap(&mut self, i: usize, j: usize) {
+        assert!(i < self.len() && j < self.len(), "index out of bounds");
+
+        if let Some((a, b)) = self.get_mut_pair(i, j) {
+            mem::swap(a, b);
+        }
+    }
+
+    /// swap two elements in the array by index,
+    /// returning the first index that is out of bounds instead of panicking
+    pub fn try_swap(&mut self, i: usize, j: usize) -> Result<(), usize> {
+        if i >= self.len() {
+            return Err(i);
+        }
+        if j >= self.len() {
+            return Err(j);
+        }
+
+        self.swap(i, j);
+        Ok(())
+    }
+
+    /// swap the first and last elements of the array
+    ///
+    /// does nothing if the array has fewer than two elements
+    pub fn swap_ends(&mut self) {
+        if self.len() < 2 {
+            return;
+        }
+
+        let last = self.len() - 1;
+        self.swap(0, last);
+    }
+
+    /// swap the contents of two full rows
+    ///
+    /// panics if either row is out of bounds or not full
+    pub fn swap_rows(&mut self, a: usize, b: usize) {
+        let full_rows = self.length / self.row_capacity;
+        assert!(a < full_rows && b < full_rows, "row out of bounds");
+
+        self.backing.swap(a, b);
+    }
+
+    /// reverse the order of the elements in the array
+    pub fn reverse(&mut self) {
+        let length = self.len();
+        for i in 0..length / 2 {
+            self.swap(i, length - 1 - i);
+        }
+    }
+
+    /// retrieves an element in the array by index
+    pub fn get(&self, index: usize) -> Option<&T> {
+        let target_row = index / self.row_capacity;
+        let column = index % self.row_capacity;
+
+        self.backing
+            .get(target_row)
+            .and_then(|x: &VecDeque<T>| x.get(column))
+    }
+
+    /// retrieves an element in the array by index, without checking that it's in bounds
+    ///
+    /// # Safety
+    ///
+    /// `index` must be less than the length of the array,
+    /// calling this with an out of bounds index is undefined behavior
+    pub unsafe fn get_unchecked(&self, index: usize) -> &T {
+        // SAFETY: the caller guarantees the index is in bounds, and every element
+        // in bounds lives at this row and column
+        unsafe {
+            self.backing
+                .get_unchecked(index / self.row_capacity)
+                .get(index % self.row_capacity)
+                .unwrap_unchecked()
+        }
+    }
+
+    /// retrieves an element in the array mutably by index,
+    /// without checking that it's in bounds
+    ///
+    /// # Safety
+    ///
+    /// `index` must be less than the length of the array,
+    /// calling this with an out of bounds index is undefined behavior
+    pub unsafe fn get_unchecked_mut(&mut self, index: usize) -> &mut T {
+        let (row, column) = (index / self.row_capacity, index % self.row_capacity);
+
+        // SAFETY: the caller guarantees the index is in bounds, and every element
+        // in bounds lives at this row and column
+        unsafe {
+            self.backing
+                .get_unchecked_mut(row)
+                .get_mut(column)
+                .unwrap_unchecked()
+        }
+    }
+
+    /// returns the row holding the element at the given index,
+    /// and the element's position within that row
+    ///
+    /// the row and position match the order of `iter_rows`
+    ///
+    /// panics if `index` is out of bounds
+    ///
+    /// ```
+    /// use igush_rs::Igush;
+    ///
+    /// let mut array = Igush::with_row_capacity(3, 0);
+    /// for i in (0..7).rev() {
+    ///     array.push_front(i);
+    /// }
+    ///
+    /// assert_eq!(array.locate(0), (0, 0));
+    /// assert_eq!(array.locate(4), (1, 1));
+    /// assert_eq!(array.locate(6), (2, 0));
+    ///
+    /// let (row, column) = array.locate(4);
+    /// assert_eq!(array.iter_rows().nth(row).unwrap().nth(column), Some(&4));
+    /// ```
+    pub fn locate(&self, index: usize) -> (usize, usize) {
+        assert!(index < self.len(), "index out of bounds");
+
+        (index / self.row_capacity, index % self.row_capacity)
+    }
+
+    /// returns a borrowed view of the given range of elements
+    ///
+    /// panics if the range is out of bounds
+    pub fn slice<R: RangeBounds<usize>>(&self, range: R) -> IgushSlice<'_, T> {
+        let (start, end) = self.range_bounds(range);
+
+        IgushSlice::new(self, start, end)
+    }
+
+    /// split the array into two borrowed views at the given index,
+    /// the first holding the elements before `mid` and the second the rest
+    ///
+    /// panics if `mid` is greater than the length of the array
+    pub fn split_at(&self, mid: usize) -> (IgushSlice<'_, T>, IgushSlice<'_, T>) {
+        self.slice(..).split_at(mid)
+    }
+
+    /// returns the first element and a view of the rest, or `None` if the array is empty
+    pub fn split_first(&self) -> Option<(&T, IgushSlice<'_, T>)> {
+        self.slice(..).split_first()
+    }
+
+    /// returns the last element and a view of the rest, or `None` if the array is empty
+    pub fn split_last(&self) -> Option<(&T, IgushSlice<'_, T>)> {
+        self.slice(..).split_last()
+    }
+
+    /// returns an iterator over views of `size` consecutive elements at a time,
+    /// the last of which is shorter if the length isn't a multiple of `size`
+    ///
+    /// panics if `size` is zero
+    pub fn chunks(&self, size: usize) -> Chunks<'_, T> {
+        assert!(size > 0, "chunk size must be non-zero");
+
+        Chunks::new(self, size)
+    }
+
+    /// returns an iterator over overlapping views of every `size` consecutive elements
+    ///
+    /// yields nothing if `size` is greater than the length of the array
+    ///
+    /// panics if `size` is zero
+    pub fn windows(&self, size: usize) -> Windows<'_, T> {
+        assert!(size > 0, "window size must be non-zero");
+
+        Windows::new(self, size)
+    }
+
+    /// clone the array into fresh rows
+    ///
+    /// unlike `clone`, none of the cloned rows wrap around inside their DEQ,
+    /// and no unused DEQs are carried over
+    pub fn clone_contiguous(&self) -> Igush<T>
+    where
+        T: Clone,
+    {
+        let mut clone = Igush::with_row_capacity(self.row_capacity, self.length);
+        for element in self {
+            clone.push_back(element.clone());
+        }
+
+        clone
+    }
+
+    /// rebuild the array with a new row width
+    ///
+    /// panics if `row_capacity` is zero
+    pub fn set_row_capacity(&mut self, row_capacity: usize) {
+        assert!(row_capacity > 0, "row capacity must be non-zero");
+
+        if row_capacity == self.row_capacity {
+            return;
+        }
+
+        let backing = mem::replace(
+            &mut self.backing,
+            Vec::with_capacity(self.length.div_ceil(row_capacity)),
+        );
+        let rows = self.rows;
+        // don't let the pushes below change the width again
+        let adaptive = mem::replace(&mut self.adaptive, false);
+
+        self.row_capacity = row_capacity;
+        self.capacity = self.backing.capacity() * row_capacity;
+        self.rows = 0;
+        self.length = 0;
+
+        for row in backing.into_iter().take(rows) {
+            for element in row {
+                self.push_back(element);
+            }
+        }
+
+        self.adaptive = adaptive;
+    }
+
+    /// rebuild the array with a row width of about `sqrt(N)` for its current length
+    pub fn rebalance(&mut self) {
+        self.set_row_capacity(auto_row_capacity(self.length));
+    }
+
+    /// set whether `push_back` and `push_front` widen the rows as the array grows
+    ///
+    /// an adaptive array rebuilds itself with a row width of about `sqrt(N)`
+    /// whenever its length reaches four times the square of the current width,
+    /// keeping insertion and removal near `O(sqrt(N))` at the cost of an
+    /// occasional `O(N)` push, similar to `Vec` reallocation
+    pub fn set_adaptive(&mut self, adaptive: bool) {
+        self.adaptive = adaptive;
+    }
+
+    /// returns true if pushes widen the rows as the array grows
+    pub fn is_adaptive(&self) -> bool {
+        self.adaptive
+    }
+
+    /// overwrite every element with a clone of the given value
+    pub fn fill(&mut self, value: T)
+    where
+        T: Clone,
+    {
+        for element in self.iter_mut() {
+            element.clone_from(&value);
+        }
+    }
+
+    /// overwrite every element with values returned by the given function
+    pub fn fill_with<F: FnMut() -> T>(&mut self, mut f: F) {
+        for element in self.iter_mut() {
+            *element = f();
+        }
+    }
+
+    /// overwrite the elements in the given range with clones of the given value
+    ///
+    /// panics if the range is out of bounds
+    pub fn fill_range<R: RangeBounds<usize>>(&mut self, range: R, value: T)
+    where
+        T: Clone,
+    {
+        let (start, end) = self.range_bounds(range);
+
+        for element in IterMut::range(&mut self.backing, self.row_capacity, start, end) {
+            element.clone_from(&value);
+        }
+    }
+
+    /// returns the elements as a single slice, if they are already stored contiguously
+    ///
+    /// this is only the case when they all fit in one row
+    /// which doesn't wrap around inside its DEQ
+    pub fn as_contiguous_slice(&self) -> Option<&[T]> {
+        match &self.backing[..self.rows] {
+            [] => Some(&[]),
+            [row] => match row.as_slices() {
+                (front, []) => Some(front),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// returns the elements as a single mutable slice,
+    /// if they are already stored contiguously
+    ///
+    /// this is only the case when they all fit in one row
+    /// which doesn't wrap around inside its DEQ
+    pub fn as_contiguous_mut_slice(&mut self) -> Option<&mut [T]> {
+        match &mut self.backing[..self.rows] {
+            [] => Some(&mut []),
+            [row] => match row.as_mut_slices() {
+                (front, []) => Some(front),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// pass every element to `f` as one mutable slice, moving them into
+    /// a single row for the call and restoring the row capacity afterward
+    ///
+    /// when there is more than one row this rebuilds the array twice, so it
+    /// costs `O(N)`; if `f` panics the array is left with a single row
+    ///
+    /// ```
+    /// use igush_rs::Igush;
+    ///
+    /// let mut array: Igush<i32> = Igush::with_row_capacity(3, 0);
+    /// for &x in [5, 2, 8, 1, 9, 3, 7].iter() {
+    ///     array.push_front(x);
+    /// }
+    ///
+    /// array.with_contiguous(|elements| elements.sort_unstable());
+    /// assert_eq!(array, [1, 2, 3, 5, 7, 8, 9]);
+    /// assert_eq!(array.row_capacity(), 3);
+    /// ```
+    pub fn with_contiguous<R, F>(&mut self, f: F) -> R
+    where
+        F: FnOnce(&mut [T]) -> R,
+    {
+        let row_capacity = self.row_capacity;
+        if self.rows > 1 {
+            self.set_row_capacity(self.length);
+        }
+
+        let result = match self.backing[..self.rows].first_mut() {
+            Some(row) => f(row.make_contiguous()),
+            None => f(&mut []),
+        };
+
+        self.set_row_capacity(row_capacity);
+
+        result
+    }
+
+    /// convert the array into a `Vec` holding the same elements in order
+    ///
+    /// when all the elements fit in one row, that row's buffer is reused,
+    /// which is `O(1)` if the row starts at the front of its buffer; otherwise
+    /// the elements are moved, within that buffer or into a new allocation
+    /// when there are several rows, which is `O(N)`
+    ///
+    /// ```
+    /// use igush_rs::Igush;
+    ///
+    /// // one row pushed from the back: the buffer is handed over as is
+    /// let mut array = Igush::with_row_capacity(8, 0);
+    /// array.extend_back(0..5);
+    /// let buffer = array.as_contiguous_slice().unwrap().as_ptr();
+    /// let vec = array.into_vec();
+    /// assert_eq!(vec, [0, 1, 2, 3, 4]);
+    /// assert_eq!(vec.as_ptr(), buffer);
+    ///
+    /// // several rows: every element is moved into a new `Vec`
+    /// let mut array = Igush::with_row_capacity(2, 0);
+    /// array.extend_back(0..5);
+    /// assert!(array.as_contiguous_slice().is_none());
+    /// assert_eq!(array.into_vec(), [0, 1, 2, 3, 4]);
+    /// ```
+    pub fn into_vec(mut self) -> Vec<T> {
+        if self.rows <= 1 {
+            return self
+                .backing
+                .drain(..)
+                .next()
+                .map(Vec::from)
+                .unwrap_or_default();
+        }
+
+        let mut vec = Vec::with_capacity(self.len());
+        for row in self.backing {
+            vec.extend(row);
+        }
+
+        vec
+    }
+
+    /// consume the array, returning a new array with `f` applied to each element
+    ///
+    /// the new array has the same row width and layout, and is built row by row
+    pub fn map<U, F: FnMut(T) -> U>(self, mut f: F) -> Igush<U> {
+        let row_capacity = self.row_capacity;
+        let backing: Vec<VecDeque<U>> = self
+            .backing
+            .into_iter()
+            .take(self.rows)
+            .map(|row| {
+                let mut mapped = VecDeque::with_capacity(row_capacity);
+                mapped.extend(row.into_iter().map(&mut f));
+                mapped
+            })
+            .collect();
+
+        Igush {
+            capacity: backing.capacity() * row_capacity,
+            backing,
+            row_capacity,
+            rows: self.rows,
+            length: self.length,
+            adaptive: self.adaptive,
+        }
+    }
+
+    /// returns an iterator over the elements of the array, front to back
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter::new(&self.backing, self.row_capacity, 0, self.length)
+    }
+
+    /// returns an iterator over every `step`-th element of the array,
+    /// starting with the first
+    ///
+    /// panics if `step` is zero
+    pub fn stride(&self, step: usize) -> Stride<'_, T> {
+        assert!(step > 0, "step must be non-zero");
+
+        Stride::new(&self.backing, self.row_capacity, self.length, step)
+    }
+
+    /// returns an iterator over mutable references to the elements of the array, front to back
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        IterMut::new(&mut self.backing[..self.rows], self.length)
+    }
+
+    /// returns an iterator over the rows in use, each of which
+    /// iterates over the elements of that row, front to back
+    ///
+    /// every row but the last is full
+    pub fn iter_rows(&self) -> Rows<'_, T> {
+        Rows::new(&self.backing[..self.rows])
+    }
+
+    /// returns an iterator over the rows in use, each as a mutable slice
+    /// of that row's elements in order
+    ///
+    /// every row but the last is full, and the last only holds the
+    /// elements in use; each row is made contiguous as it is yielded
+    pub fn rows_mut(&mut self) -> RowsMut<'_, T> {
+        RowsMut::new(&mut self.backing[..self.rows])
+    }
+
+    /// consume the array, returning an iterator over its rows in use,
+    /// each as a `Vec` of that row's elements in order
+    ///
+    /// every row but the last is full
+    pub fn into_rows(mut self) -> IntoRows<T> {
+        self.backing.truncate(self.rows);
+
+        IntoRows::new(self.backing)
+    }
+
+    /// describe the internal layout of the array, for debugging
+    ///
+    /// unlike the `Debug` output, this includes the row width and
+    /// the contents of every DEQ, including unused ones
+    pub fn debug_structure(&self) -> String
+    where
+        T: fmt::Debug,
+    {
+        format!(
+            "Igush {{ row_capacity: {}, rows: {}, length: {}, backing: {:?} }}",
+            self.row_capacity, self.rows, self.length, self.backing
+        )
+    }
+
+    /// returns true if the array contains an element equal to the given value
+    pub fn contains(&self, x: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        self.iter().any(|element| element == x)
+    }
+
+    /// returns the index of the first element matching the predicate
+    pub fn position<P: FnMut(&T) -> bool>(&self, pred: P) -> Option<usize> {
+        self.iter().position(pred)
+    }
+
+    /// returns the index of the last element matching the predicate
+    pub fn rposition<P: FnMut(&T) -> bool>(&self, pred: P) -> Option<usize> {
+        self.iter().rposition(pred)
+    }
+
+    /// returns the largest element, or the last of several equally large ones
+    pub fn max_element(&self) -> Option<&T>
+    where
+        T: Ord,
+    {
+        self.iter().max()
+    }
+
+    /// returns the smallest element, or the first of several equally small ones
+    pub fn min_element(&self) -> Option<&T>
+    where
+        T: Ord,
+    {
+        self.iter().min()
+    }
+
+    /// returns the element with the largest key,
+    /// or the last of several with equally large keys
+    pub fn max_element_by_key<B: Ord, F: FnMut(&T) -> B>(&self, mut f: F) -> Option<&T> {
+        self.iter().max_by_key(|element| f(element))
+    }
+
+    /// returns the element with the smallest key,
+    /// or the first of several with equally small keys
+    pub fn min_element_by_key<B: Ord, F: FnMut(&T) -> B>(&self, mut f: F) -> Option<&T> {
+        self.iter().min_by_key(|element| f(element))
+    }
+
+    /// add up the elements of the array
+    ///
+    /// ```
+    /// use igush_rs::Igush;
+    ///
+    /// let mut array = Igush::with_row_capacity(2, 0);
+    /// array.push_back(3);
+    /// array.push_front(2);
+    /// array.push_back(4);
+    /// array.push_front(1);
+    ///
+    /// assert_eq!(array.sum::<i32>(), 10);
+    /// ```
+    pub fn sum<S: Sum<T>>(&self) -> S
+    where
+        T: Copy,
+    {
+        self.iter().copied().sum()
+    }
+
+    /// multiply together the elements of the array
+    ///
+    /// ```
+    /// use igush_rs::Igush;
+    ///
+    /// let mut array = Igush::with_row_capacity(2, 0);
+    /// array.push_back(3);
+    /// array.push_front(2);
+    /// array.push_back(4);
+    /// array.push_front(1);
+    ///
+    /// assert_eq!(array.product::<i32>(), 24);
+    /// ```
+    pub fn product<P: Product<T>>(&self) -> P
+    where
+        T: Copy,
+    {
+        self.iter().copied().product()
+    }
+
+    /// binary searches a sorted array for the given element
+    ///
+    /// returns `Ok` with the index of a matching element, or `Err` with the
+    /// index where the element could be inserted to keep the array sorted
+    pub fn binary_search(&self, x: &T) -> Result<usize, usize>
+    where
+        T: Ord,
+    {
+        self.binary_search_by(|element| element.cmp(x))
+    }
+
+    /// binary searches a sorted array with a comparator function
+    ///
+    /// the comparator should return the ordering of the element relative to the target
+    pub fn binary_search_by<F>(&self, mut f: F) -> Result<usize, usize>
+    where
+        F: FnMut(&T) -> Ordering,
+    {
+        let mut low = 0;
+        let mut high = self.len();
+
+        while low < high {
+            let mid = low + (high - low) / 2;
+
+            match f(self.get(mid).unwrap()) {
+                Ordering::Less => low = mid + 1,
+                Ordering::Greater => high = mid,
+                Ordering::Equal => return Ok(mid),
+            }
+        }
+
+        Err(low)
+    }
+
+    /// returns the index of the first element for which the predicate is false,
+    /// assuming every element it holds for comes before every one it doesn't
+    pub fn partition_point<P: FnMut(&T) -> bool>(&self, mut pred: P) -> usize {
+        // a comparator that never reports equality always ends the search
+        self.binary_search_by(|element| {
+            if pred(element) {
+                Ordering::Less
+            } else {
+                Ordering::Greater
+            }
+        })
+        .unwrap_err()
+    }
+
+    /// index of the first element of a sorted array that isn't less than `x`
+    fn lower_bound(&self, x: &T) -> usize
+    where
+        T: Ord,
+    {
+        self.partition_point(|element| element < x)
+    }
+
+    /// search a sorted array for the range of indices holding elements equal to `x`
+    ///
+    /// if there are none, the range is empty and starts where `x` could be inserted
+    /// to keep the array sorted
+    pub fn equal_range(&self, x: &T) -> Range<usize>
+    where
+        T: Ord,
+    {
+        let start = self.lower_bound(x);
+        let end = self.partition_point(|element| element <= x);
+
+        start..end
+    }
+
+    /// search a sorted array for `x`, returning the lowest index holding an equal element
+    ///
+    /// unlike `binary_search`, the index returned among several equal elements
+    /// is always the first
+    pub fn index_of_sorted(&self, x: &T) -> Option<usize>
+    where
+        T: Ord,
+    {
+        let start = self.lower_bound(x);
+
+        match self.get(start) {
+            Some(element) if element == x => Some(start),
+            _ => None,
+        }
+    }
+
+    /// binary searches an array sorted by the given key extraction function
+    pub fn binary_search_by_key<B, F>(&self, b: &B, mut f: F) -> Result<usize, usize>
+    where
+        F: FnMut(&T) -> B,
+        B: Ord,
+    {
+        self.binary_search_by(|element| f(element).cmp(b))
+    }
+}
+
+impl<T: Clone> Clone for Igush<T> {
+    fn clone(&self) -> Self {
+        // only the rows in use are cloned, so the clone's capacity has to
+        // come from its own backing rather than from `self`
+        let backing = self.backing[..self.rows].to_vec();
+
+        Igush {
+            capacity: backing.capacity() * self.row_capacity,
+            backing,
+            row_capacity: self.row_capacity,
+            rows: self.rows,
+            length: self.length,
+            adaptive: self.adaptive,
+        }
+    }
+
+    /// overwrite this array with a copy of `source`, reusing its DEQs where possible
+    fn clone_from(&mut self, source: &Self) {
+        if self.backing.len() < source.rows {
+            self.backing.resize_with(source.rows, VecDeque::new);
+        }
+
+        let (used, spare) = self.backing.split_at_mut(source.rows);
+        for (row, source_row) in used.iter_mut().zip(&source.backing) {
+            row.clone_from(source_row);
+        }
+        for row in spare {
+            row.clear();
+        }
+
+        self.row_capacity = source.row_capacity;
+        self.capacity = self.backing.capacity() * self.row_capacity;
+        self.rows = source.rows;
+        self.length = source.length;
+        self.adaptive = source.adaptive;
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Igush<T> {
+    /// lists the elements in order, like a slice
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self).finish()
+    }
+}
+
+impl<T> Default for Igush<T> {
+    /// create an empty array, the same as `Igush::new`
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> FromIterator<T> for Igush<T> {
+    /// collect into a new array, choosing the row capacity from the iterator's size hint
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let iter = iter.into_iter();
+        let (lower, _) = iter.size_hint();
+
+        Igush::from_iter_with_row_capacity(auto_row_capacity(lower), iter)
+    }
+}
+
+impl<'a, T: 'a + Copy> FromIterator<&'a T> for Igush<T> {
+    /// collect copies of the referenced elements into a new array,
+    /// choosing the row capacity from the iterator's size hint
+    ///
+    /// ```
+    /// use igush_rs::Igush;
+    ///
+    /// let array: Igush<i32> = [1, 2, 3].iter().collect();
+    /// assert_eq!(array, [1, 2, 3]);
+    /// ```
+    fn from_iter<I: IntoIterator<Item = &'a T>>(iter: I) -> Self {
+        iter.into_iter().copied().collect()
+    }
+}
+
+impl<T> FromIterator<Igush<T>> for Igush<T> {
+    /// join the collected arrays into one, the same as `Igush::concat`
+    fn from_iter<I: IntoIterator<Item = Igush<T>>>(iter: I) -> Self {
+        Igush::concat(iter.into_iter().collect())
+    }
+}
+
+impl<T> Extend<T> for Igush<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.extend_back(iter);
+    }
+}
+
+impl<'a, T: 'a + Copy> Extend<&'a T> for Igush<T> {
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        self.extend_back(iter.into_iter().copied());
+    }
+}
+
+impl<T, const N: usize> From<[T; N]> for Igush<T> {
+    fn from(array: [T; N]) -> Self {
+        IntoIterator::into_iter(array).collect()
+    }
+}
+
+impl<T> From<Vec<T>> for Igush<T> {
+    fn from(vec: Vec<T>) -> Self {
+        vec.into_iter().collect()
+    }
+}
+
+impl<T> From<Igush<T>> for Vec<T> {
+    fn from(array: Igush<T>) -> Self {
+        array.into_vec()
+    }
+}
+
+impl<T> From<Box<[T]>> for Igush<T> {
+    fn from(boxed: Box<[T]>) -> Self {
+        Vec::from(boxed).into()
+    }
+}
+
+impl<T> From<Igush<T>> for Box<[T]> {
+    fn from(array: Igush<T>) -> Self {
+        Vec::from(array).into_boxed_slice()
+    }
+}
+
+impl<T> From<VecDeque<T>> for Igush<T> {
+    fn from(deque: VecDeque<T>) -> Self {
+        deque.into_iter().collect()
+    }
+}
+
+impl<T> From<Igush<T>> for VecDeque<T> {
+    fn from(array: Igush<T>) -> Self {
+        let mut deque = VecDeque::with_capacity(array.len());
+        for row in array.backing {
+            deque.extend(row);
+        }
+
+        deque
+    }
+}
+
+impl<T> IntoIterator for Igush<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(mut self) -> IntoIter<T> {
+        self.backing.truncate(self.rows);
+
+        IntoIter::new(self.backing, self.length)
+    }
+}
+
+impl<'a, T> IntoIterator for &'a Igush<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut Igush<T> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> IterMut<'a, T> {
+        self.iter_mut()
+    }
+}
+
+impl<T> Index<usize> for Igush<T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &T {
+        self.get(index).expect("index out of bounds")
+    }
+}
+
+impl<T> IndexMut<usize> for Igush<T> {
+    fn index_mut(&mut self, index: usize) -> &mut T {
+        self.get_mut(index).expect("index out of bounds")
+    }
+}
+
+impl<T: PartialEq> PartialEq for Igush<T> {
+    /// compares elements in order, regardless of row width or layout
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.iter().eq(other.iter())
+    }
+}
+impl<T: Eq> Eq for Igush<T> {}
+
+impl<T: PartialOrd> PartialOrd for Igush<T> {
+    /// compares elements lexicographically, like a slice
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        if self.row_capacity != other.row_capacity {
+            return self.iter().partial_cmp(other.iter());
+        }
+
+        // the rows line up, so compare a whole row at a time
+        let rows = self.backing[..self.rows].iter();
+        for (a, b) in rows.zip(&other.backing[..other.rows]) {
+            match a.partial_cmp(b) {
+                Some(Ordering::Equal) => {}
+                ordering => return ordering,
+            }
+        }
+
+        // one array is a prefix of the other
+        self.len().partial_cmp(&other.len())
+    }
+}
+
+impl<T: Ord> Ord for Igush<T> {
+    /// compares elements lexicographically, like a slice
+    fn cmp(&self, other: &Self) -> Ordering {
+        if self.row_capacity != other.row_capacity {
+            return self.iter().cmp(other.iter());
+        }
+
+        // the rows line up, so compare a whole row at a time
+        let rows = self.backing[..self.rows].iter();
+        for (a, b) in rows.zip(&other.backing[..other.rows]) {
+            match a.cmp(b) {
+                Ordering::Equal => {}
+                ordering => return ordering,
+            }
+        }
+
+        // one array is a prefix of the other
+        self.len().cmp(&other.len())
+    }
+}
+
+impl<T: Hash> Hash for Igush<T> {
+    /// hashes the length and then each element in order, like a slice
+    ///
+    /// only the elements are hashed, so arrays that compare equal hash the same
+    /// regardless of row width or layout, making them usable as map keys
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.len().hash(state);
+        for element in self {
+            element.hash(state);
+        }
+    }
+}
+
+impl<A: PartialEq<B>, B, const N: usize> PartialEq<[B; N]> for Igush<A> {
+    fn eq(&self, other: &[B; N]) -> bool {
+        self.len() == N && self.iter().zip(other.iter()).all(|(a, b)| a == b)
+    }
+}
+
+impl<A: PartialEq<B>, B> PartialEq<VecDeque<B>> for Igush<A> {
+    fn eq(&self, other: &VecDeque<B>) -> bool {
+        self.len() == other.len() && self.iter().zip(other.iter()).all(|(a, b)| a == b)
+    }
+}
+
+impl<A: PartialEq<B>, B> PartialEq<LinkedList<B>> for Igush<A> {
+    fn eq(&self, other: &LinkedList<B>) -> bool {
+        self.len() == other.len() && self.iter().zip(other.iter()).all(|(a, b)| a == b)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn create() {
+        let created: Igush<i32> = Igush::with_row_capacity(32, 0);
+
+        assert_eq!(created.capacity(), 0);
+        assert_eq!(created.len(), 0);
+
+        let created: Igush<i32> = Igush::with_row_capacity(5, 5);
+
+        assert_eq!(created.capacity(), 5);
+        assert_eq!(created.len(), 0);
+
+        let created: Igush<i32> = Igush::with_row_capacity(10, 11);
+
+        assert_eq!(created.capacity(), 20);
+        assert_eq!(created.len(), 0);
+    }
+
+    #[test]
+    fn push_back() {
+        let mut array: Igush<i32> = Igush::with_row_capacity(5, 0);
+
+        for _ in 0..20 {
+            array.push_back(1);
+        }
+
+        assert_eq!(array.len(), 20);
+    }
+
+    #[test]
+    fn push_front() {
+        let mut array: Igush<i32> = Igush::with_row_capacity(5, 0);
+
+        for i in 0..20 {
+            array.push_front(i);
+        }
+
+        assert_eq!(array.len(), 20);
+    }
+
+    #[test]
+    fn get() {
+        let mut array: Igush<i32> = Igush::with_row_capacity(5, 0);
+
+        assert_eq!(array.get(0), None);
+
+        array.push_back(1);
+
+        assert_eq!(array.get(0), Some(&1));
+    }
+
+    #[test]
+    fn get_mut() {
+        let mut array: Igush<i32> = Igush::with_row_capacity(5, 0);
+
+        assert_eq!(array.get_mut(0), None);
+
+        array.push_back(1);
+
+        assert_eq!(array.get_mut(0), Some(&mut 1));
+
+        let x = array.get_mut(0).unwrap();
+        *x = 5;
+
+        assert_eq!(array.get_mut(0), Some(&mut 5));
+    }
+
+    #[test]
+    fn insert() {
+        let mut array: Igush<i32> = Igush::with_row_capacity(5, 0);
+
+        array.push_back(0);
+        array.push_back(1);
+        array.push_back(2);
+        array.push_back(3);
 
         for i in (102..122).rev() {
             array.insert(2, i);
         }
 
-        assert_eq!(array.len(), 24);
-        assert_eq!(array.get(0), Some(&0));
-        assert_eq!(array.get(1), Some(&1));
+        assert_eq!(array.len(), 24);
+        assert_eq!(array.get(0), Some(&0));
+        assert_eq!(array.get(1), Some(&1));
+
+        for i in 2..22 {
+            let num = 100 + (i as i32);
+            assert_eq!(array.get(i), Some(&num));
+        }
+
+        assert_eq!(array.get(22), Some(&2));
+        assert_eq!(array.get(23), Some(&3));
+
+        array.insert(0, 7);
+
+        assert_eq!(array.get(0), Some(&7));
+
+        array.insert(array.len(), 8);
+
+        assert_eq!(array.get(array.len() - 1), Some(&8));
+    }
+
+    #[test]
+    fn pop_back() {
+        let mut array: Igush<i32> = Igush::with_row_capacity(5, 0);
+
+        assert_eq!(array.pop_back(), None);
+
+        for i in 0..20 {
+            array.push_back(i);
+        }
+
+        assert_eq!(array.len(), 20);
+
+        assert_eq!(array.pop_back(), Some(19));
+
+        assert_eq!(array.len(), 19);
+    }
+
+    #[test]
+    fn pop_front() {
+        let mut array: Igush<i32> = Igush::with_row_capacity(5, 0);
+
+        assert_eq!(array.pop_front(), None);
+
+        for i in 0..20 {
+            array.push_back(i);
+        }
+
+        assert_eq!(array.len(), 20);
+
+        assert_eq!(array.pop_front(), Some(0));
+
+        assert_eq!(array.len(), 19);
+    }
+
+    #[test]
+    fn remove() {
+        let mut array: Igush<i32> = Igush::with_row_capacity(5, 0);
+
+        assert_eq!(array.remove(0), None);
+
+        for i in 0..20 {
+            array.push_back(i);
+        }
+
+        assert_eq!(array.len(), 20);
+
+        assert_eq!(array.remove(10), Some(10));
+
+        assert_eq!(array.len(), 19);
+
+        assert_eq!(array.remove(18), Some(19));
+
+        assert_eq!(array.remove(0), Some(0));
+
+        assert_eq!(array.remove(20), None);
+    }
+
+    #[test]
+    fn remove_every_index() {
+        for rc in 1..5 {
+            for len in 1..13 {
+                for index in 0..len {
+                    let mut array: Igush<usize> = Igush::with_row_capacity(rc, 0);
+                    array.extend_back(0..len);
+                    let mut model: Vec<usize> = (0..len).collect();
+
+                    assert_eq!(array.remove(index), Some(model.remove(index)));
+                    check_invariants(&array);
+                    assert!(array.iter().eq(model.iter()));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn equal() {
+        let mut a: Igush<i32> = Igush::with_row_capacity(5, 0);
+        let mut b: Igush<i32> = Igush::with_row_capacity(5, 0);
+
+        for i in 0..20 {
+            a.push_back(i);
+        }
+        for i in (0..20).rev() {
+            b.push_front(i);
+        }
+
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds")]
+    fn out_of_bounds_insert() {
+        let mut array: Igush<i32> = Igush::with_row_capacity(5, 0);
+
+        array.insert(1, 3);
+    }
+
+    #[test]
+    fn pop_across_rows() {
+        let mut array: Igush<i32> = Igush::with_row_capacity(5, 0);
+
+        for i in 0..12 {
+            array.push_back(i);
+        }
+
+        for i in (0..12).rev() {
+            assert_eq!(array.pop_back(), Some(i));
+        }
+        assert_eq!(array.pop_back(), None);
+
+        for i in 0..12 {
+            array.push_back(i);
+        }
+
+        for i in 0..12 {
+            assert_eq!(array.pop_front(), Some(i));
+        }
+        assert_eq!(array.pop_front(), None);
+        assert!(array.is_empty());
+    }
+
+    #[test]
+    fn preallocated_rows() {
+        let mut array: Igush<i32> = Igush::with_row_capacity(5, 12);
+
+        for i in 0..12 {
+            array.push_back(i);
+        }
+
+        for i in 0..12 {
+            assert_eq!(array.get(i), Some(&(i as i32)));
+        }
+        assert_eq!(array.capacity(), 15);
+    }
+
+    #[test]
+    fn rotate_left() {
+        let mut array: Igush<i32> = Igush::with_row_capacity(5, 0);
+
+        array.rotate_left(0);
+        assert!(array.is_empty());
+
+        for mid in 0..=12 {
+            let mut rotated: Igush<i32> = Igush::with_row_capacity(5, 0);
+            for i in 0..12 {
+                rotated.push_back(i);
+            }
+            rotated.rotate_left(mid);
+
+            assert_eq!(rotated.len(), 12);
+            for i in 0..12 {
+                let expected = ((i + mid) % 12) as i32;
+                assert_eq!(rotated.get(i), Some(&expected));
+            }
+        }
+    }
+
+    #[test]
+    fn rotate_preallocated() {
+        // rotation pops and pushes across the end row, so preallocated rows
+        // must only be counted once they hold elements
+        let mut array: Igush<i32> = Igush::with_row_capacity(5, 30);
+        for i in 0..12 {
+            array.push_back(i);
+        }
+        assert_eq!(array.rows, 3);
+
+        for &mid in [3, 9, 5, 10].iter() {
+            array.rotate_left(mid);
+            assert_eq!(array.rows, 3);
+            assert_eq!(array.capacity(), 30);
+            assert!(array.backing[3..].iter().all(|row| row.is_empty()));
+        }
+        for i in 0..12 {
+            let expected = ((i + 3) % 12) as i32;
+            assert_eq!(array.get(i), Some(&expected));
+        }
+    }
+
+    #[test]
+    fn rotate_right() {
+        let mut array: Igush<i32> = Igush::with_row_capacity(5, 0);
+
+        array.rotate_right(0);
+        assert!(array.is_empty());
+
+        for i in 0..12 {
+            array.push_back(i);
+        }
+
+        array.rotate_right(7);
+
+        for i in 0..12 {
+            let expected = ((i + 5) % 12) as i32;
+            assert_eq!(array.get(i), Some(&expected));
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "mid out of bounds")]
+    fn out_of_bounds_rotate() {
+        let mut array: Igush<i32> = Igush::with_row_capacity(5, 0);
+
+        array.push_back(1);
+        array.rotate_left(2);
+    }
+
+    #[test]
+    fn binary_search() {
+        let mut array: Igush<i32> = Igush::with_row_capacity(5, 0);
+
+        assert_eq!(array.binary_search(&3), Err(0));
+
+        // interleave pushes so the rows are not contiguous
+        for i in 0..10 {
+            array.push_back(20 + i * 2);
+            array.push_front(18 - i * 2);
+        }
+
+        assert_eq!(array.binary_search(&0), Ok(0));
+        assert_eq!(array.binary_search(&20), Ok(10));
+        assert_eq!(array.binary_search(&38), Ok(19));
+        assert_eq!(array.binary_search(&-1), Err(0));
+        assert_eq!(array.binary_search(&7), Err(4));
+        assert_eq!(array.binary_search(&39), Err(20));
+
+        let index = array.binary_search(&7).unwrap_err();
+        array.insert(index, 7);
+        assert_eq!(array.get(index), Some(&7));
+        assert_eq!(array.get(index - 1), Some(&6));
+        assert_eq!(array.get(index + 1), Some(&8));
+    }
+
+    #[test]
+    fn binary_search_by_key() {
+        let mut array: Igush<(i32, char)> = Igush::with_row_capacity(3, 0);
+
+        for (i, c) in "abcdefgh".char_indices().rev() {
+            array.push_front((i as i32, c));
+        }
+
+        assert_eq!(array.binary_search_by_key(&5, |&(i, _)| i), Ok(5));
+        assert_eq!(array.binary_search_by_key(&9, |&(i, _)| i), Err(8));
+        assert_eq!(array.binary_search_by(|&(_, c)| c.cmp(&'c')), Ok(2));
+    }
+
+    #[test]
+    fn iter() {
+        let mut array: Igush<i32> = Igush::with_row_capacity(5, 0);
+
+        assert_eq!(array.iter().next(), None);
+
+        for i in 0..6 {
+            array.push_back(6 + i);
+            array.push_front(5 - i);
+        }
+
+        let mut iter = array.iter();
+        assert_eq!(iter.len(), 12);
+        assert_eq!(iter.next(), Some(&0));
+        assert_eq!(iter.next_back(), Some(&11));
+        assert_eq!(iter.len(), 10);
+
+        let collected: Vec<i32> = iter.copied().collect();
+        assert_eq!(collected, (1..11).collect::<Vec<_>>());
+
+        let reversed: Vec<i32> = array.iter().rev().copied().collect();
+        assert_eq!(reversed, (0..12).rev().collect::<Vec<_>>());
+
+        let mut expected = 0;
+        for x in &array {
+            assert_eq!(*x, expected);
+            expected += 1;
+        }
+        assert_eq!(expected, 12);
+    }
+
+    #[test]
+    fn iter_mut() {
+        let mut array: Igush<i32> = Igush::with_row_capacity(5, 0);
+
+        for i in 0..12 {
+            array.push_back(i);
+        }
+
+        for x in &mut array {
+            *x *= 2;
+        }
+
+        let mut iter = array.iter_mut();
+        *iter.next_back().unwrap() = -1;
+        assert_eq!(iter.len(), 11);
+
+        for i in 0..11 {
+            assert_eq!(array.get(i), Some(&(i as i32 * 2)));
+        }
+        assert_eq!(array.get(11), Some(&-1));
+    }
+
+    #[test]
+    fn iter_mut_disjoint() {
+        let mut array: Igush<i32> = Igush::with_row_capacity(3, 0);
+
+        for i in 0..10 {
+            array.push_front(i);
+        }
+
+        // hold every reference at once, taken from both ends
+        let mut iter = array.iter_mut();
+        let mut refs = Vec::new();
+        while let Some(back) = iter.next_back() {
+            refs.push(back);
+            if let Some(front) = iter.next() {
+                refs.push(front);
+            }
+        }
+        assert_eq!(refs.len(), 10);
+
+        for x in refs {
+            *x += 100;
+        }
+
+        for i in 0..10 {
+            assert_eq!(array.get(i), Some(&(109 - i as i32)));
+        }
+    }
+
+    #[test]
+    fn from_iter() {
+        let array: Igush<i32> = (0..400).collect();
+
+        assert_eq!(array.len(), 400);
+        assert_eq!(array.row_capacity, 20);
+        assert!(array.iter().copied().eq(0..400));
+
+        let array: Igush<i32> = (0..5).collect();
+
+        assert_eq!(array.row_capacity, 10);
+        assert!(array.iter().copied().eq(0..5));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trip() {
+        let mut array: Igush<i32> = Igush::with_row_capacity(3, 0);
+
+        for i in 0..5 {
+            array.push_back(5 + i);
+            array.push_front(4 - i);
+        }
+
+        let json = serde_json::to_string(&array).unwrap();
+        assert_eq!(json, "[0,1,2,3,4,5,6,7,8,9]");
+
+        let parsed: Igush<i32> = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed.len(), 10);
+        assert_eq!(parsed.row_capacity, 10);
+        assert!(parsed.iter().eq(array.iter()));
+    }
+
+    #[test]
+    fn append() {
+        let mut a: Igush<i32> = Igush::with_row_capacity(100, 0);
+        let mut b: Igush<i32> = Igush::with_row_capacity(100, 0);
+
+        for i in 0..10_000 {
+            a.push_back(i);
+            b.push_back(10_000 + i);
+        }
+
+        a.append(&mut b);
+
+        assert_eq!(a.len(), 20_000);
+        assert!(b.is_empty());
+        assert_eq!(b.iter().next(), None);
+        assert!(a.iter().copied().eq(0..20_000));
+
+        // a partial end row forces the elements to be moved individually
+        let mut c: Igush<i32> = Igush::with_row_capacity(3, 0);
+        let mut d: Igush<i32> = Igush::with_row_capacity(3, 0);
+
+        for i in 0..4 {
+            c.push_back(i);
+        }
+        for i in (4..10).rev() {
+            d.push_front(i);
+        }
+
+        c.append(&mut d);
+
+        assert_eq!(c.len(), 10);
+        assert!(d.is_empty());
+        assert!(c.iter().copied().eq(0..10));
+
+        d.push_back(1);
+        assert_eq!(d.get(0), Some(&1));
+    }
+
+    #[test]
+    fn drain() {
+        let mut array: Igush<i32> = (0..10_000).collect();
+
+        let drained: Vec<i32> = array.drain(10..9990).collect();
+
+        assert!(drained.into_iter().eq(10..9990));
+        assert_eq!(array.len(), 20);
+        assert!(array.iter().copied().eq((0..10).chain(9990..10_000)));
+
+        // dropping the iterator early still removes the range
+        let mut drain = array.drain(..=4);
+        assert_eq!(drain.next(), Some(0));
+        drop(drain);
+
+        assert_eq!(array.len(), 15);
+        assert!(array.iter().copied().eq((5..10).chain(9990..10_000)));
+
+        assert_eq!(array.drain(15..).len(), 0);
+        assert_eq!(array.drain(..).len(), 15);
+        assert!(array.is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "range end out of bounds")]
+    fn out_of_bounds_drain() {
+        let mut array: Igush<i32> = (0..5).collect();
+
+        array.drain(2..6);
+    }
+
+    #[test]
+    fn try_reserve() {
+        let mut array: Igush<i32> = Igush::with_row_capacity(5, 0);
+
+        assert!(array.try_reserve(12).is_ok());
+        assert!(array.capacity() >= 12);
+
+        for i in 0..12 {
+            array.push_back(i);
+        }
+        assert!(array.iter().copied().eq(0..12));
+
+        assert!(array.try_reserve_exact(8).is_ok());
+        assert_eq!(array.capacity(), 20);
+
+        assert!(array.try_reserve(usize::MAX).is_err());
+        assert!(array.try_reserve_exact(usize::MAX / 2).is_err());
+        assert_eq!(array.len(), 12);
+    }
+
+    #[test]
+    fn default() {
+        #[derive(Default)]
+        struct Holder {
+            array: Igush<i32>,
+        }
+
+        let mut array: Igush<i32> = Igush::default();
+
+        assert!(array.is_empty());
+        assert_eq!(array.row_capacity, DEFAULT_ROW_CAPACITY);
+
+        for i in 0..25 {
+            array.push_back(i);
+        }
+        assert!(array.iter().copied().eq(0..25));
+
+        let holder = Holder::default();
+        assert!(holder.array.is_empty());
+
+        let mut taken = std::mem::take(&mut array);
+        assert!(array.is_empty());
+        assert_eq!(taken.pop_back(), Some(24));
+    }
+
+    #[test]
+    fn from_array() {
+        let array = Igush::from([1, 2, 3]);
+
+        assert_eq!(array.len(), 3);
+        assert_eq!(array, [1, 2, 3]);
+        assert_ne!(array, [1, 2]);
+        assert_ne!(array, [1, 2, 4]);
+
+        let empty: Igush<i32> = Igush::from([]);
+        assert!(empty.is_empty());
+        assert_eq!(empty, [0; 0]);
+
+        let mut long = [0; 50];
+        for (i, x) in long.iter_mut().enumerate() {
+            *x = i;
+        }
+
+        let mut array: Igush<usize> = Igush::with_row_capacity(7, 0);
+        for i in (0..50).rev() {
+            array.push_front(i);
+        }
+        assert_eq!(array, long);
+        assert_eq!(Igush::from(long), long);
+    }
+
+    #[test]
+    fn from_vec() {
+        let array = Igush::from(vec![4, 5, 6]);
+
+        assert_eq!(array, [4, 5, 6]);
+    }
+
+    #[test]
+    fn dedup() {
+        let mut array: Igush<i32> = Igush::with_row_capacity(3, 0);
+
+        for &x in [1, 1, 2, 3, 3, 3, 4, 1, 1].iter().rev() {
+            array.push_front(x);
+        }
+
+        array.dedup();
+
+        assert_eq!(array.len(), 5);
+        assert_eq!(array, [1, 2, 3, 4, 1]);
+
+        array.push_back(5);
+        assert_eq!(array, [1, 2, 3, 4, 1, 5]);
+    }
+
+    #[test]
+    fn dedup_by() {
+        let mut array: Igush<i32> = Igush::with_row_capacity(3, 0);
+
+        for &x in [10, 11, 20, -21, 22, 30, 12].iter().rev() {
+            array.push_front(x);
+        }
+
+        array.dedup_by_key(|x| x.abs() / 10);
+        assert_eq!(array, [10, 20, 30, 12]);
+
+        array.dedup_by(|later, earlier| *later < *earlier);
+        assert_eq!(array, [10, 20, 30]);
+    }
+
+    #[test]
+    fn dedup_by_panic() {
+        let elements = [1, 1, 2, 2, 3, 3, 4, 4];
+        let same_bucket = |later: &mut i32, earlier: &mut i32| {
+            assert!(*later != 3);
+            later == earlier
+        };
+
+        let mut array: Igush<i32> = Igush::with_row_capacity(3, 0);
+        for &x in elements.iter().rev() {
+            array.push_front(x);
+        }
+        let mut model = elements.to_vec();
+
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            array.dedup_by(same_bucket);
+        }));
+        assert!(result.is_err());
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            model.dedup_by(same_bucket);
+        }));
+        assert!(result.is_err());
+
+        // the array keeps whatever `Vec::dedup_by` kept, rather than being emptied
+        assert!(!array.is_empty());
+        assert!(array.iter().eq(model.iter()));
+    }
+
+    #[test]
+    fn swap_remove() {
+        let mut array: Igush<i32> = Igush::with_row_capacity(5, 0);
+
+        assert_eq!(array.swap_remove(0), None);
+
+        for i in (0..12).rev() {
+            array.push_front(i);
+        }
+
+        assert_eq!(array.swap_remove(2), Some(2));
+        assert_eq!(array.len(), 11);
+        assert_eq!(array.get(2), Some(&11));
+
+        assert_eq!(array.swap_remove(10), Some(10));
+        assert_eq!(array.len(), 10);
+
+        assert_eq!(array.swap_remove(10), None);
+        assert_eq!(array, [0, 1, 11, 3, 4, 5, 6, 7, 8, 9]);
+    }
+
+    #[test]
+    fn clone_contiguous() {
+        let mut array: Igush<i32> = Igush::with_row_capacity(5, 0);
+
+        for i in (0..12).rev() {
+            array.push_front(i);
+        }
+        assert!(array
+            .backing
+            .iter()
+            .any(|row| !row.as_slices().1.is_empty()));
+
+        let clone = array.clone();
+        check_invariants(&clone);
+        assert_eq!(clone, array);
+
+        let contiguous = array.clone_contiguous();
+        assert_eq!(contiguous, array);
+        assert_eq!(contiguous.backing.len(), 3);
+        assert!(contiguous
+            .backing
+            .iter()
+            .all(|row| row.as_slices().1.is_empty()));
+    }
+
+    #[test]
+    fn get_mut_pair() {
+        let mut array: Igush<i32> = Igush::with_row_capacity(5, 0);
+
+        assert_eq!(array.get_mut_pair(0, 1), None);
+
+        for i in (0..12).rev() {
+            array.push_front(i);
+        }
+
+        assert_eq!(array.get_mut_pair(3, 3), None);
+        assert_eq!(array.get_mut_pair(3, 12), None);
+        assert_eq!(array.get_mut_pair(12, 3), None);
+
+        // same row
+        let (a, b) = array.get_mut_pair(4, 1).unwrap();
+        assert_eq!((*a, *b), (4, 1));
+        std::mem::swap(a, b);
+
+        // different rows
+        let (a, b) = array.get_mut_pair(2, 11).unwrap();
+        assert_eq!((*a, *b), (2, 11));
+        std::mem::swap(a, b);
+
+        assert_eq!(array, [0, 4, 11, 3, 1, 5, 6, 7, 8, 9, 10, 2]);
+    }
+
+    #[test]
+    fn index() {
+        let mut array: Igush<i32> = Igush::with_row_capacity(5, 0);
+
+        for i in (0..12).rev() {
+            array.push_front(i);
+        }
+
+        assert_eq!(array[0], 0);
+        assert_eq!(array[7], 7);
+
+        array[7] = 70;
+        assert_eq!(array[7], 70);
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds")]
+    fn out_of_bounds_index() {
+        let array: Igush<i32> = Igush::from([1, 2, 3]);
+
+        let _ = array[3];
+    }
+
+    #[test]
+    fn slice() {
+        let mut array: Igush<i32> = Igush::with_row_capacity(5, 0);
+
+        for i in (0..17).rev() {
+            array.push_front(i);
+        }
+
+        let middle = array.slice(3..14);
+        assert_eq!(middle.len(), 11);
+        assert_eq!(middle[0], 3);
+        assert_eq!(middle.get(10), Some(&13));
+        assert_eq!(middle.get(11), None);
+        assert!(middle.iter().copied().eq(3..14));
+        assert!(middle.iter().rev().copied().eq((3..14).rev()));
+
+        assert!(array.slice(6..=8).iter().copied().eq(6..9));
+        assert!(array.slice(..4).iter().copied().eq(0..4));
+        assert!(array.slice(15..).iter().copied().eq(15..17));
+        assert!(array.slice(..).iter().copied().eq(0..17));
+        assert!(array.slice(5..5).is_empty());
+
+        let mut sum = 0;
+        for x in array.slice(10..12) {
+            sum += x;
+        }
+        assert_eq!(sum, 21);
+    }
+
+    #[test]
+    #[should_panic(expected = "range end out of bounds")]
+    fn out_of_bounds_slice() {
+        let array: Igush<i32> = Igush::from([1, 2, 3]);
+
+        array.slice(1..4);
+    }
+
+    #[test]
+    fn position() {
+        let mut array: Igush<i32> = Igush::with_row_capacity(4, 0);
+
+        assert_eq!(array.position(|_| true), None);
+
+        for i in 0..10 {
+            array.push_front(i % 5);
+        }
+        // [4, 3, 2, 1, 0, 4, 3, 2, 1, 0]
+
+        assert_eq!(array.position(|&x| x == 2), Some(2));
+        assert_eq!(array.rposition(|&x| x == 2), Some(7));
+        assert_eq!(array.position(|&x| x < 1), Some(4));
+        assert_eq!(array.rposition(|&x| x == 4), Some(5));
+        assert_eq!(array.position(|&x| x > 4), None);
+        assert_eq!(array.rposition(|&x| x > 4), None);
+    }
+
+    #[test]
+    fn contains() {
+        let mut array: Igush<i32> = Igush::with_row_capacity(3, 0);
+
+        assert!(!array.contains(&0));
+
+        let mut model = VecDeque::new();
+        for i in 0..10 {
+            array.push_back(i);
+            model.push_back(i);
+            array.push_front(-i);
+            model.push_front(-i);
+        }
+        array.pop_back();
+        model.pop_back();
+        array.pop_front();
+        model.pop_front();
+        array.remove(5);
+        model.remove(5);
+        array.insert(3, 100);
+        model.insert(3, 100);
+
+        for x in -20..120 {
+            assert_eq!(array.contains(&x), model.contains(&x));
+        }
+        assert!(array.contains(&100));
+        assert!(!array.contains(&9));
+        assert!(!array.contains(&-9));
+    }
+
+    #[test]
+    fn set_row_capacity() {
+        let mut array: Igush<i32> = Igush::with_row_capacity(3, 0);
+
+        for i in (0..100).rev() {
+            array.push_front(i);
+        }
+
+        array.set_row_capacity(7);
+
+        assert_eq!(array.row_capacity, 7);
+        assert_eq!(array.len(), 100);
+        for i in 0..100 {
+            assert_eq!(array.get(i), Some(&(i as i32)));
+        }
+
+        array.push_front(-1);
+        array.insert(50, -2);
+        assert_eq!(array.get(0), Some(&-1));
+        assert_eq!(array.get(50), Some(&-2));
+        assert_eq!(array.get(101), Some(&99));
+    }
+
+    #[test]
+    fn rebalance() {
+        let mut array: Igush<i32> = Igush::with_row_capacity(2, 0);
+
+        for i in 0..900 {
+            array.push_back(i);
+        }
+
+        array.rebalance();
+
+        assert_eq!(array.row_capacity, 30);
+        assert!(array.iter().copied().eq(0..900));
+    }
+
+    #[test]
+    #[should_panic(expected = "row capacity must be non-zero")]
+    fn zero_row_capacity() {
+        let mut array: Igush<i32> = Igush::new();
+
+        array.set_row_capacity(0);
+    }
+
+    #[test]
+    fn row_capacity() {
+        assert_eq!(Igush::<i32>::with_row_capacity(7, 0).row_capacity(), 7);
+        assert_eq!(Igush::<i32>::new().row_capacity(), DEFAULT_ROW_CAPACITY);
+
+        assert_eq!(Igush::from(vec![0; 100]).row_capacity(), 10);
+        assert_eq!(Igush::from(vec![0; 2500]).row_capacity(), 50);
+        assert_eq!((0..10_000).collect::<Igush<i32>>().row_capacity(), 100);
+
+        let mut array = Igush::from(vec![0; 2500]);
+        array.set_row_capacity(3);
+        assert_eq!(array.row_capacity(), 3);
+    }
+
+    #[test]
+    fn adaptive() {
+        let mut array: Igush<u32> = Igush::with_row_capacity(2, 0);
+        array.set_adaptive(true);
+        assert!(array.is_adaptive());
+
+        for i in 0..1_000_000 {
+            array.push_back(i);
+        }
+
+        let width = array.row_capacity();
+        assert!((500..=1000).contains(&width), "width {}", width);
+        assert!(array.is_adaptive());
+
+        for i in 0..100 {
+            array.push_front(i);
+        }
+        assert_eq!(array.len(), 1_000_100);
+        assert_eq!(array[0], 99);
+        assert_eq!(array[100], 0);
+        assert_eq!(array[1_000_099], 999_999);
+
+        let mut fixed: Igush<u32> = Igush::with_row_capacity(2, 0);
+        for i in 0..1000 {
+            fixed.push_back(i);
+        }
+        assert_eq!(fixed.row_capacity(), 2);
+    }
+
+    fn hash_of<T: Hash>(value: &T) -> u64 {
+        use std::collections::hash_map::DefaultHasher;
+
+        let mut hasher = DefaultHasher::new();
+        value.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    #[test]
+    fn hash() {
+        let mut a: Igush<i32> = Igush::with_row_capacity(5, 0);
+        let mut b: Igush<i32> = Igush::with_row_capacity(5, 40);
+        let c: Igush<i32> = (0..12).collect();
+
+        for i in 0..12 {
+            a.push_back(i);
+        }
+        for i in (0..12).rev() {
+            b.push_front(i);
+        }
+
+        assert_eq!(a, b);
+        assert_eq!(a, c);
+        assert_eq!(hash_of(&a), hash_of(&b));
+        assert_eq!(hash_of(&a), hash_of(&c));
+
+        b.pop_back();
+        assert_ne!(a, b);
+        assert_ne!(hash_of(&a), hash_of(&b));
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn par_iter() {
+        use rayon::prelude::*;
+
+        let mut array: Igush<u64> = Igush::with_row_capacity(100, 0);
+
+        for i in 0..50_000 {
+            array.push_back(50_000 + i);
+            array.push_front(49_999 - i);
+        }
+
+        let sequential: u64 = array.iter().sum();
+        let parallel: u64 = array.par_iter().sum();
+        assert_eq!(parallel, sequential);
+
+        assert!(array.par_iter().enumerate().all(|(i, &x)| x == i as u64));
+
+        array.par_iter_mut().for_each(|x| *x *= 2);
+        assert!(array.iter().copied().eq((0..100_000).map(|x| x * 2)));
+
+        array.pop_back();
+        array
+            .par_iter_mut()
+            .enumerate()
+            .for_each(|(i, x)| *x -= i as u64);
+        assert!(array.iter().copied().eq(0..99_999));
+    }
+
+    #[test]
+    fn extract_if() {
+        let mut array: Igush<i32> = Igush::with_row_capacity(3, 0);
+
+        for i in (0..10).rev() {
+            array.push_front(i);
+        }
+
+        let evens: Vec<i32> = array.extract_if(|x| *x % 2 == 0).collect();
+
+        assert_eq!(evens, [0, 2, 4, 6, 8]);
+        assert_eq!(array, [1, 3, 5, 7, 9]);
+
+        // dropping the iterator early still removes the matches
+        let mut extracted = array.extract_if(|x| *x > 4);
+        assert_eq!(extracted.next(), Some(5));
+        drop(extracted);
+
+        assert_eq!(array, [1, 3]);
+    }
+
+    #[test]
+    fn extract_if_panic() {
+        let mut array: Igush<i32> = Igush::with_row_capacity(3, 0);
+        for i in (0..10).rev() {
+            array.push_front(i);
+        }
+
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            array.extract_if(|x| {
+                assert!(*x != 6);
+                *x % 2 == 0
+            });
+        }));
+
+        // the elements extracted before the panic are gone, the rest stay
+        assert!(result.is_err());
+        assert_eq!(array, [1, 3, 5, 6, 7, 8, 9]);
+    }
+
+    #[test]
+    fn fill() {
+        let mut array: Igush<i32> = Igush::with_row_capacity(3, 0);
+
+        array.fill(1);
+        assert!(array.is_empty());
+
+        for i in (0..10).rev() {
+            array.push_front(i);
+        }
+
+        array.fill(7);
+        assert_eq!(array.len(), 10);
+        assert!(array.iter().all(|&x| x == 7));
+
+        let mut next = 0;
+        array.fill_with(|| {
+            next += 1;
+            next
+        });
+        assert_eq!(array.len(), 10);
+        assert!(array.iter().copied().eq(1..=10));
+    }
+
+    #[test]
+    fn swap() {
+        let mut array: Igush<i32> = Igush::from([0, 1, 2, 3]);
+
+        array.swap(0, 3);
+        array.swap(2, 2);
+        assert_eq!(array, [3, 1, 2, 0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds")]
+    fn out_of_bounds_swap() {
+        let mut array: Igush<i32> = Igush::from([0, 1, 2, 3]);
+
+        array.swap(4, 4);
+    }
+
+    #[test]
+    fn reverse() {
+        for length in 0..13 {
+            let mut array: Igush<i32> = Igush::with_row_capacity(3, 0);
+            for i in (0..length).rev() {
+                array.push_front(i);
+            }
+
+            array.reverse();
+
+            assert_eq!(array.len(), length as usize);
+            for i in 0..length {
+                assert_eq!(array[i as usize], length - 1 - i);
+            }
+        }
+    }
+
+    #[test]
+    fn swap_ends() {
+        let mut array: Igush<i32> = Igush::with_row_capacity(3, 0);
+
+        array.swap_ends();
+        assert!(array.is_empty());
+
+        array.push_back(1);
+        array.swap_ends();
+        assert_eq!(array, [1]);
+
+        array.push_back(2);
+        array.swap_ends();
+        assert_eq!(array, [2, 1]);
+
+        for i in 3..11 {
+            array.push_front(i);
+        }
+        array.swap_ends();
+        assert_eq!(array, [1, 9, 8, 7, 6, 5, 4, 3, 2, 10]);
+    }
+
+    #[test]
+    fn shrink_to() {
+        let mut array: Igush<i32> = Igush::with_row_capacity(5, 100);
+
+        for i in 0..7 {
+            array.push_back(i);
+        }
+
+        array.shrink_to(20);
+
+        assert!(array.capacity() >= 20);
+        assert!(array.capacity() < 100);
+        assert_eq!(array.backing.len(), 4);
+        assert!(array.iter().copied().eq(0..7));
+
+        array.shrink_to(0);
+        assert!(array.capacity() >= 7);
+        assert_eq!(array.backing.len(), 2);
+
+        // never shrinks below the length
+        array.push_back(7);
+        array.push_back(8);
+        array.push_back(9);
+        array.shrink_to(0);
+        assert!(array.capacity() >= 10);
+        assert!(array.iter().copied().eq(0..10));
+    }
+
+    #[test]
+    fn as_contiguous_slice() {
+        let mut array: Igush<i32> = Igush::with_row_capacity(5, 0);
+
+        assert_eq!(array.as_contiguous_slice(), Some(&[][..]));
+
+        for i in 0..4 {
+            array.push_back(i);
+        }
+        assert_eq!(array.as_contiguous_slice(), Some(&[0, 1, 2, 3][..]));
+
+        array.as_contiguous_mut_slice().unwrap().reverse();
+        assert_eq!(array, [3, 2, 1, 0]);
+
+        array.push_front(4);
+        assert_eq!(array.as_contiguous_slice(), None);
+        assert_eq!(array.as_contiguous_mut_slice(), None);
+
+        let mut array: Igush<i32> = (0..6).collect();
+        array.set_row_capacity(5);
+        assert_eq!(array.as_contiguous_slice(), None);
+    }
+
+    #[test]
+    fn from_iter_with_row_capacity() {
+        let array = Igush::from_iter_with_row_capacity(7, 0..100);
+
+        assert_eq!(array.row_capacity(), 7);
+        assert_eq!(array.len(), 100);
+        assert!(array.iter().copied().eq(0..100));
+
+        // a poor size hint doesn't affect the width
+        let array = Igush::from_iter_with_row_capacity(7, (0..100).filter(|x| x % 2 == 0));
+
+        assert_eq!(array.row_capacity(), 7);
+        assert!(array.iter().copied().eq((0..100).step_by(2)));
+    }
+
+    #[test]
+    fn sort() {
+        let mut array: Igush<i32> = Igush::with_row_capacity(3, 0);
+
+        for &x in [5, -3, 9, 0, 2, 2, -8, 7].iter() {
+            array.push_front(x);
+        }
+
+        array.sort();
+        assert_eq!(array, [-8, -3, 0, 2, 2, 5, 7, 9]);
+
+        array.sort_by(|a, b| b.cmp(a));
+        assert_eq!(array, [9, 7, 5, 2, 2, 0, -3, -8]);
+    }
+
+    #[test]
+    fn sort_by_panic() {
+        let mut array: Igush<i32> = Igush::with_row_capacity(3, 0);
+        for x in (0..20).rev() {
+            array.push_front((x * 7) % 20);
+        }
+
+        let mut comparisons = 0;
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            array.sort_by(|a, b| {
+                comparisons += 1;
+                assert!(comparisons < 30);
+                a.cmp(b)
+            });
+        }));
+
+        // like `Vec::sort_by`, a panic leaves a permutation of the elements
+        assert!(result.is_err());
+        assert_eq!(array.len(), 20);
+        let mut elements: Vec<i32> = array.iter().copied().collect();
+        elements.sort_unstable();
+        assert!(elements.into_iter().eq(0..20));
+    }
+
+    #[test]
+    fn sort_by_key_is_stable() {
+        let mut array: Igush<(u8, char)> = Igush::with_row_capacity(3, 0);
+
+        for &record in [(2, 'a'), (1, 'b'), (2, 'c'), (0, 'd'), (1, 'e'), (2, 'f')]
+            .iter()
+            .rev()
+        {
+            array.push_front(record);
+        }
+
+        array.sort_by_key(|&(key, _)| key);
+
+        assert_eq!(
+            array,
+            [(0, 'd'), (1, 'b'), (1, 'e'), (2, 'a'), (2, 'c'), (2, 'f')]
+        );
+    }
+
+    #[test]
+    fn insert_sorted() {
+        let mut array: Igush<u32> = Igush::with_row_capacity(4, 0);
+
+        // visit 0..50 in a scrambled order, with some duplicates
+        for i in 0..60 {
+            let x = (i * 37) % 50;
+            let index = array.insert_sorted(x);
+            assert_eq!(array[index], x);
+        }
+
+        assert_eq!(array.len(), 60);
+        for i in 1..array.len() {
+            assert!(array[i - 1] <= array[i]);
+        }
+    }
+
+    #[test]
+    fn split_off() {
+        let mut array: Igush<i32> = Igush::with_row_capacity(100, 0);
+
+        for i in (0..10_000).rev() {
+            array.push_front(i);
+        }
+
+        let tail = array.split_off(9990);
+
+        assert_eq!(tail.len(), 10);
+        assert_eq!(tail.row_capacity(), 100);
+        assert!(tail.iter().copied().eq(9990..10_000));
+        assert_eq!(array.len(), 9990);
+        assert!(array.iter().copied().eq(0..9990));
+
+        // on a row boundary
+        let mut tail = array.split_off(9000);
+
+        assert!(tail.iter().copied().eq(9000..9990));
+        assert!(array.iter().copied().eq(0..9000));
+
+        tail.push_back(-1);
+        array.push_back(-2);
+        assert_eq!(tail[990], -1);
+        assert_eq!(array[9000], -2);
+
+        let rest = array.split_off(0);
+        assert!(array.is_empty());
+        assert_eq!(rest.len(), 9001);
+
+        let mut small: Igush<i32> = Igush::from([1, 2, 3]);
+        assert!(small.split_off(3).is_empty());
+        assert_eq!(small, [1, 2, 3]);
+    }
+
+    #[test]
+    #[should_panic(expected = "at out of bounds")]
+    fn out_of_bounds_split_off() {
+        let mut array: Igush<i32> = Igush::from([1, 2, 3]);
+
+        array.split_off(4);
+    }
+
+    #[test]
+    fn truncate() {
+        let mut array: Igush<i32> = (0..30).collect();
+        array.set_row_capacity(4);
+
+        array.truncate(40);
+        assert_eq!(array.len(), 30);
+
+        array.truncate(9);
+        assert!(array.iter().copied().eq(0..9));
+
+        array.push_back(9);
+        assert!(array.iter().copied().eq(0..10));
+
+        array.truncate(0);
+        assert!(array.is_empty());
+    }
+
+    #[test]
+    fn truncate_front() {
+        for len in 0..=23 {
+            // contiguous
+            let mut array = Igush::from_iter_with_row_capacity(5, 0..23);
+            array.truncate_front(len);
+
+            assert_eq!(array.len(), len);
+            assert!(array.iter().copied().eq((23 - len)..23));
+
+            // non-contiguous
+            let mut array: Igush<usize> = Igush::with_row_capacity(5, 0);
+            for i in (0..23).rev() {
+                array.push_front(i);
+            }
+            array.truncate_front(len);
+
+            assert_eq!(array.len(), len);
+            assert!(array.iter().copied().eq((23 - len)..23));
+
+            array.push_back(23);
+            array.push_front(0);
+            assert_eq!(array[0], 0);
+            assert_eq!(array[len + 1], 23);
+        }
+    }
+
+    #[test]
+    fn extend_back() {
+        let mut array: Igush<i32> = Igush::with_row_capacity(100, 0);
+
+        array.push_back(-1);
+        array.extend_back(0..100_000);
+
+        assert_eq!(array.len(), 100_001);
+        assert_eq!(array[0], -1);
+        assert!(array.iter().skip(1).copied().eq(0..100_000));
+        assert!(array.capacity() >= 100_001);
+        assert!(array.capacity() <= 2 * 100_001 + 100);
+
+        array.extend((0..10).filter(|x| x % 3 == 0));
+        assert!(array.iter().skip(100_001).copied().eq([0, 3, 6, 9]));
+    }
+
+    #[test]
+    fn reserve() {
+        let mut array: Igush<i32> = Igush::with_row_capacity(5, 0);
+
+        array.reserve(23);
+        let capacity = array.capacity();
+        assert!(capacity >= 23);
+
+        array.extend_back(0..23);
+        assert_eq!(array.capacity(), capacity);
+    }
+
+    #[test]
+    #[should_panic(expected = "capacity exceeded")]
+    fn reserve_overflow() {
+        let mut array: Igush<i32> = Igush::from([1]);
+
+        array.reserve(usize::MAX);
+    }
+
+    #[test]
+    fn retain() {
+        let mut array: Igush<i32> = Igush::with_row_capacity(3, 0);
+
+        for i in (0..10).rev() {
+            array.push_front(i);
+        }
+
+        array.retain(|x| x % 3 != 0);
+        assert_eq!(array, [1, 2, 4, 5, 7, 8]);
+    }
+
+    #[test]
+    fn retain_mut() {
+        let mut array: Igush<i32> = Igush::with_row_capacity(3, 0);
+
+        for &x in [3, -1, 4, -1, -5, 9, 2, -6].iter().rev() {
+            array.push_front(x);
+        }
+
+        array.retain_mut(|x| {
+            *x *= 2;
+            *x >= 0
+        });
+
+        assert_eq!(array, [6, 8, 18, 4]);
+    }
+
+    #[test]
+    fn retain_mut_panic() {
+        let mut array: Igush<i32> = Igush::with_row_capacity(3, 0);
+        array.extend_back(0..10);
+
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            array.retain_mut(|x| {
+                assert!(*x != 6);
+                *x % 2 == 0
+            });
+        }));
+
+        assert!(result.is_err());
+        check_invariants(&array);
+        assert_eq!(array, [0, 2, 4, 6, 7, 8, 9]);
+    }
+
+    #[test]
+    fn retain_sparse() {
+        let mut array: Igush<usize> = Igush::with_row_capacity(7, 0);
+        array.extend_back(0..500);
+
+        let mut visited = Vec::new();
+        array.retain(|&x| {
+            visited.push(x);
+            x % 100 != 99
+        });
+
+        assert!(visited.into_iter().eq(0..500));
+        assert!(array.iter().copied().eq((0..500).filter(|x| x % 100 != 99)));
+        check_invariants(&array);
+
+        array.retain(|_| false);
+        assert!(array.is_empty());
+        check_invariants(&array);
+    }
+
+    #[test]
+    fn insert_many() {
+        let mut array: Igush<i32> = Igush::with_row_capacity(3, 0);
+
+        for i in (0..10).rev() {
+            array.push_front(i);
+        }
+
+        array.insert_many(4, 100..105);
+        assert_eq!(array.len(), 15);
+        assert!(array
+            .iter()
+            .copied()
+            .eq((0..4).chain(100..105).chain(4..10)));
+
+        array.insert_many(0, vec![-1]);
+        array.insert_many(16, vec![-2]);
+        array.insert_many(8, Vec::new());
+        assert_eq!(array[0], -1);
+        assert_eq!(array[16], -2);
+        assert_eq!(array.len(), 17);
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds")]
+    fn out_of_bounds_insert_many() {
+        let mut array: Igush<i32> = Igush::from([1, 2, 3]);
+
+        array.insert_many(4, 0..2);
+    }
+
+    #[test]
+    fn splice() {
+        let mut array: Igush<i32> = Igush::with_row_capacity(3, 0);
+
+        for i in (0..10).rev() {
+            array.push_front(i);
+        }
+
+        let removed: Vec<i32> = array.splice(2..5, 100..105).collect();
+        assert_eq!(removed, [2, 3, 4]);
+        assert!(array
+            .iter()
+            .copied()
+            .eq((0..2).chain(100..105).chain(5..10)));
+
+        let removed: Vec<i32> = array.splice(2..=6, vec![-1, -2, -3]).collect();
+        assert_eq!(removed, [100, 101, 102, 103, 104]);
+        assert_eq!(array, [0, 1, -1, -2, -3, 5, 6, 7, 8, 9]);
+
+        drop(array.splice(.., None));
+        assert!(array.is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "range start is greater than range end")]
+    fn out_of_bounds_splice() {
+        let mut array: Igush<i32> = Igush::from([1, 2, 3]);
+
+        #[allow(clippy::reversed_empty_ranges)]
+        array.splice(2..1, None);
+    }
+
+    #[test]
+    fn from_vec_deque() {
+        // wrap the deque's internal buffer around
+        let mut deque: VecDeque<i32> = VecDeque::with_capacity(8);
+        for i in 0..6 {
+            deque.push_back(i);
+        }
+        for _ in 0..4 {
+            deque.pop_front();
+        }
+        for i in 6..12 {
+            deque.push_back(i);
+        }
+        deque.push_front(3);
+        assert!(!deque.as_slices().1.is_empty());
+
+        let array = Igush::from(deque.clone());
+        assert_eq!(array, deque);
+        assert!(array.iter().eq(deque.iter()));
+
+        let mut array: Igush<i32> = Igush::with_row_capacity(3, 0);
+        for &x in deque.iter().rev() {
+            array.push_front(x);
+        }
+        let round_trip = VecDeque::from(array);
+        assert_eq!(round_trip, deque);
+
+        assert_eq!(VecDeque::from(Igush::<i32>::new()), VecDeque::new());
+        assert_ne!(Igush::from([1, 2]), VecDeque::from(vec![1, 2, 3]));
+    }
+
+    #[test]
+    fn boxed_slice() {
+        let boxed: Box<[i32]> = (0..25).collect();
+
+        let array = Igush::from(boxed.clone());
+        assert!(array.iter().eq(boxed.iter()));
+
+        let mut array: Igush<i32> = Igush::with_row_capacity(4, 0);
+        for &x in boxed.iter().rev() {
+            array.push_front(x);
+        }
+        assert_eq!(Box::<[i32]>::from(array), boxed);
+
+        let empty: Box<[i32]> = Box::new([]);
+        assert!(Igush::from(empty).is_empty());
+        assert!(Box::<[i32]>::from(Igush::new()).is_empty());
+        assert_eq!(Vec::from(Igush::from([1, 2, 3])), [1, 2, 3]);
+    }
+
+    #[test]
+    fn drain_ends() {
+        let mut array: Igush<i32> = Igush::with_row_capacity(4, 0);
+        for i in (0..20).rev() {
+            array.push_front(i);
+        }
+
+        assert!(array.drain_front(6).eq(0..6));
+        assert!(array.iter().copied().eq(6..20));
+        assert!(array.drain_back(5).eq(15..20));
+        assert!(array.iter().copied().eq(6..15));
+
+        assert!(array.drain_front(0).next().is_none());
+        assert!(array.drain_back(0).next().is_none());
+        assert_eq!(array.len(), 9);
+
+        assert!(array.drain_front(100).eq(6..15));
+        assert!(array.is_empty());
+
+        let mut array = Igush::from([1, 2, 3]);
+        assert!(array.drain_back(100).eq([1, 2, 3]));
+        assert!(array.is_empty());
+        assert!(array.drain_back(1).next().is_none());
+    }
+
+    #[test]
+    fn rows() {
+        let mut array: Igush<u64> = Igush::with_row_capacity(5, 0);
+        assert_eq!(array.rows(), 0);
+        assert_eq!(array.memory_usage(), 0);
+
+        for i in 0..23 {
+            array.push_back(i);
+            assert_eq!(array.rows(), array.len().div_ceil(5));
+        }
+        for _ in 0..23 {
+            array.pop_front();
+            assert_eq!(array.rows(), array.len().div_ceil(5));
+        }
+
+        let array: Igush<u64> = Igush::with_row_capacity(5, 23);
+        assert_eq!(array.rows(), 0);
+        assert!(array.rows_capacity() >= 5);
+        assert!(array.memory_usage() >= 25 * mem::size_of::<u64>());
+    }
+
+    /// verify the row layout invariants that every operation must maintain
+    fn check_invariants<T>(array: &Igush<T>) {
+        let rc = array.row_capacity;
+
+        assert!(rc > 0);
+        assert_eq!(array.rows, array.length.div_ceil(rc));
+        assert!(array.rows <= array.backing.len());
+        assert_eq!(array.capacity, array.backing.capacity() * rc);
+
+        for (i, row) in array.backing.iter().enumerate() {
+            if i + 1 < array.rows {
+                assert_eq!(row.len(), rc, "row {} is not full", i);
+            } else if i + 1 == array.rows {
+                assert!(
+                    !row.is_empty() && row.len() <= rc,
+                    "end row {} is invalid",
+                    i
+                );
+            } else {
+                assert!(row.is_empty(), "spare row {} is not empty", i);
+            }
+        }
+
+        let total: usize = array.backing.iter().map(VecDeque::len).sum();
+        assert_eq!(total, array.length);
+    }
+
+    #[test]
+    fn fuzz_invariants() {
+        // small linear congruential generator, so the test is deterministic
+        let mut state: u64 = 0x2545_f491_4f6c_dd1d;
+        let mut next = |bound: usize| {
+            state = state
+                .wrapping_mul(6_364_136_223_846_793_005)
+                .wrapping_add(1_442_695_040_888_963_407);
+            ((state >> 33) as usize) % bound.max(1)
+        };
+
+        for rc in 1..6 {
+            let mut array: Igush<usize> = Igush::with_row_capacity(rc, next(20));
+            let mut model: Vec<usize> = Vec::new();
+
+            for step in 0..2000 {
+                let len = model.len();
+                match next(16) {
+                    0 | 1 => {
+                        array.push_back(step);
+                        model.push(step);
+                    }
+                    2 | 3 => {
+                        array.push_front(step);
+                        model.insert(0, step);
+                    }
+                    4 | 5 => {
+                        let index = next(len + 1);
+                        array.insert(index, step);
+                        model.insert(index, step);
+                    }
+                    6 => {
+                        assert_eq!(array.pop_back(), model.pop());
+                    }
+                    7 => {
+                        let expected = if model.is_empty() {
+                            None
+                        } else {
+                            Some(model.remove(0))
+                        };
+                        assert_eq!(array.pop_front(), expected);
+                    }
+                    8 if len > 0 => {
+                        let index = next(len);
+                        assert_eq!(array.remove(index), Some(model.remove(index)));
+                    }
+                    9 if len > 0 => {
+                        let index = next(len);
+                        assert_eq!(array.swap_remove(index), Some(model.swap_remove(index)));
+                    }
+                    10 => {
+                        let start = next(len + 1);
+                        let end = start + next(len - start + 1);
+                        assert!(array.drain(start..end).eq(model.drain(start..end)));
+                    }
+                    11 => {
+                        let at = next(len + 1);
+                        let mut tail = array.split_off(at);
+                        check_invariants(&array);
+                        check_invariants(&tail);
+                        array.append(&mut tail);
+                        check_invariants(&tail);
+                    }
+                    12 => {
+                        let mid = next(len + 1);
+                        array.rotate_left(mid);
+                        model.rotate_left(mid);
+                    }
+                    13 => {
+                        let index = next(len + 1);
+                        let count = next(2 * rc + 2);
+                        array.insert_many(index, step..step + count);
+                        model.splice(index..index, step..step + count);
+                    }
+                    14 => {
+                        let n = next(2 * rc + 2);
+                        let n = n.min(len);
+                        assert!(array.drain_front(n).eq(model.drain(..n)));
+                    }
+                    15 if step % 5 == 0 => {
+                        let modulus = next(4) + 2;
+                        array.retain(|x| x % modulus != 0);
+                        model.retain(|x| x % modulus != 0);
+                    }
+                    _ => {
+                        let new_rc = next(6) + 1;
+                        array.set_row_capacity(new_rc);
+                    }
+                }
+
+                check_invariants(&array);
+                assert_eq!(array.len(), model.len());
+                assert!(array.iter().eq(model.iter()));
+            }
+        }
+    }
+
+    #[test]
+    fn with_contiguous() {
+        let mut array: Igush<i32> = Igush::with_row_capacity(3, 0);
+        for &x in [5, 2, 8, 1, 9, 3, 7].iter() {
+            array.push_front(x);
+        }
+
+        let length = array.with_contiguous(|elements| {
+            elements.sort_unstable();
+            elements.len()
+        });
+        assert_eq!(length, 7);
+        check_invariants(&array);
+        assert_eq!(array, [1, 2, 3, 5, 7, 8, 9]);
+        assert_eq!(array.row_capacity(), 3);
+
+        array.push_front(0);
+        assert_eq!(array, [0, 1, 2, 3, 5, 7, 8, 9]);
+
+        // a single wrapped row is straightened out in place
+        let mut array: Igush<i32> = Igush::with_row_capacity(4, 0);
+        array.push_back(2);
+        array.push_front(1);
+        array.with_contiguous(|elements| assert_eq!(elements, [1, 2]));
+        assert_eq!(array.row_capacity(), 4);
+
+        let mut empty: Igush<i32> = Igush::new();
+        assert!(empty.with_contiguous(|elements| elements.is_empty()));
+    }
+
+    #[test]
+    fn into_iter() {
+        let mut array: Igush<String> = Igush::with_row_capacity(3, 20);
+        for i in (0..10).rev() {
+            array.push_front(i.to_string());
+        }
+
+        let mut iter = array.clone().into_iter();
+        assert_eq!(iter.len(), 10);
+        assert_eq!(iter.next().as_deref(), Some("0"));
+        assert_eq!(iter.next_back().as_deref(), Some("9"));
+        assert_eq!(iter.len(), 8);
+        assert!(iter.map(|s| s.parse::<i32>().unwrap()).eq(1..9));
+
+        assert!(array.into_iter().rev().map(|s| s.len()).eq(vec![1; 10]));
+    }
+
+    #[test]
+    fn concat() {
+        let mut first: Igush<i32> = Igush::with_row_capacity(3, 0);
+        for i in (0..7).rev() {
+            first.push_front(i);
+        }
+        let mut second: Igush<i32> = Igush::with_row_capacity(4, 0);
+        for i in (7..9).rev() {
+            second.push_front(i);
+        }
+        let mut third: Igush<i32> = Igush::with_row_capacity(2, 0);
+        for i in 9..20 {
+            third.push_back(i);
+        }
+        third.remove(0);
+        third.insert(0, 9);
+
+        let arrays = vec![first.clone(), Igush::new(), second.clone(), third.clone()];
+        let joined = Igush::concat(arrays);
+        assert!(joined.iter().copied().eq(0..20));
+        check_invariants(&joined);
+
+        let joined: Igush<i32> = vec![first, second, third].into_iter().collect();
+        assert!(joined.iter().copied().eq(0..20));
+
+        assert!(Igush::<i32>::concat(Vec::new()).is_empty());
+    }
+
+    #[test]
+    fn chunks() {
+        let mut array: Igush<i32> = Igush::with_row_capacity(3, 0);
+        for i in (0..11).rev() {
+            array.push_front(i);
+        }
+
+        let chunks: Vec<Vec<i32>> = array
+            .chunks(4)
+            .map(|chunk| chunk.iter().copied().collect())
+            .collect();
+        assert_eq!(chunks, [vec![0, 1, 2, 3], vec![4, 5, 6, 7], vec![8, 9, 10]]);
+        assert_eq!(array.chunks(4).len(), 3);
+
+        let mut chunks = array.chunks(4);
+        assert_eq!(chunks.next_back().unwrap().len(), 3);
+        assert_eq!(chunks.next_back().unwrap()[0], 4);
+        assert_eq!(chunks.next().unwrap()[3], 3);
+        assert!(chunks.next().is_none());
+
+        assert_eq!(array.chunks(11).len(), 1);
+        assert_eq!(array.chunks(100).next().unwrap().len(), 11);
+        assert!(Igush::<i32>::new().chunks(2).next().is_none());
+    }
+
+    #[test]
+    #[should_panic(expected = "chunk size must be non-zero")]
+    fn zero_chunk_size() {
+        Igush::from([1, 2, 3]).chunks(0);
+    }
+
+    #[test]
+    fn windows() {
+        let mut array: Igush<i32> = Igush::with_row_capacity(3, 0);
+        for i in (0..10).rev() {
+            array.push_front(i);
+        }
+
+        assert_eq!(array.windows(4).len(), array.len() - 4 + 1);
+        for (i, window) in array.windows(4).enumerate() {
+            assert!(window.iter().copied().eq(i as i32..i as i32 + 4));
+        }
+
+        let mut windows = array.windows(9);
+        assert_eq!(windows.next_back().unwrap()[0], 1);
+        assert_eq!(windows.next().unwrap()[8], 8);
+        assert!(windows.next().is_none());
+
+        assert_eq!(array.windows(10).len(), 1);
+        assert_eq!(array.windows(11).len(), 0);
+        assert!(array.windows(11).next().is_none());
+        assert_eq!(array.windows(1).len(), 10);
+    }
+
+    #[test]
+    #[should_panic(expected = "window size must be non-zero")]
+    fn zero_window_size() {
+        Igush::from([1, 2, 3]).windows(0);
+    }
+
+    #[test]
+    fn get_disjoint_mut() {
+        let mut array: Igush<i32> = Igush::with_row_capacity(3, 0);
+        for i in (0..10).rev() {
+            array.push_front(i);
+        }
+
+        let [a, b, c, d] = array.get_disjoint_mut([7, 1, 2, 9]).unwrap();
+        assert_eq!((*a, *b, *c, *d), (7, 1, 2, 9));
+        std::mem::swap(a, b);
+        *c += 100;
+        *d = -1;
+        assert_eq!(array, [0, 7, 102, 3, 4, 5, 6, 1, 8, -1]);
+
+        assert!(array.get_disjoint_mut([3, 5, 3]).is_none());
+        assert!(array.get_disjoint_mut([0, 10]).is_none());
+        assert!(array.get_disjoint_mut([0, 0]).is_none());
+        assert_eq!(array.get_disjoint_mut([]), Some([]));
+        assert_eq!(array.get_disjoint_mut([4]), Some([&mut 4]));
+
+        // permute every element through the returned references
+        let mut permuted = array.clone();
+        let refs = permuted
+            .get_disjoint_mut([9, 8, 7, 6, 5, 4, 3, 2, 1, 0])
+            .unwrap();
+        for (r, x) in IntoIterator::into_iter(refs).zip(array.iter()) {
+            *r = *x;
+        }
+        array.reverse();
+        assert_eq!(permuted, array);
+    }
+
+    #[test]
+    fn try_insert() {
+        let mut array: Igush<String> = Igush::with_row_capacity(2, 0);
+        assert_eq!(array.try_insert(0, "b".to_string()), Ok(()));
+        assert_eq!(array.try_insert(0, "a".to_string()), Ok(()));
+        assert_eq!(array.try_insert(2, "c".to_string()), Ok(()));
+
+        let rejected = array.try_insert(4, "z".to_string());
+        assert_eq!(rejected, Err((4, "z".to_string())));
+        assert_eq!(array, ["a", "b", "c"].map(String::from));
+    }
+
+    #[test]
+    fn try_swap() {
+        let mut array = Igush::from([1, 2, 3, 4]);
+
+        assert_eq!(array.try_swap(0, 3), Ok(()));
+        assert_eq!(array.try_swap(2, 2), Ok(()));
+        assert_eq!(array.try_swap(4, 0), Err(4));
+        assert_eq!(array.try_swap(1, 7), Err(7));
+        assert_eq!(array.try_swap(9, 8), Err(9));
+        assert_eq!(array, [4, 2, 3, 1]);
+    }
+
+    #[test]
+    fn rotate_whole_rows() {
+        for rc in 1..5 {
+            for len in 0..20 {
+                for mid in (0..=len).filter(|mid| mid % rc == 0) {
+                    let mut array: Igush<usize> = Igush::with_row_capacity(rc, 0);
+                    for i in (0..len).rev() {
+                        array.push_front(i);
+                    }
+                    let mut model: Vec<usize> = (0..len).collect();
+
+                    array.rotate_left(mid);
+                    model.rotate_left(mid);
+                    check_invariants(&array);
+                    assert!(array.iter().eq(model.iter()));
+
+                    array.rotate_right(mid);
+                    model.rotate_right(mid);
+                    check_invariants(&array);
+                    assert!(array.iter().eq(model.iter()));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn equal_linked_list() {
+        let mut array: Igush<i32> = Igush::with_row_capacity(3, 0);
+        for i in (0..8).rev() {
+            array.push_front(i);
+        }
+
+        let list: LinkedList<i32> = (0..8).collect();
+        assert_eq!(array, list);
+
+        let shorter: LinkedList<i32> = (0..7).collect();
+        assert_ne!(array, shorter);
+
+        array[3] = -3;
+        assert_ne!(array, list);
+        assert_eq!(Igush::<i32>::new(), LinkedList::<i32>::new());
+    }
+
+    #[test]
+    fn with_capacity() {
+        let array: Igush<i32> = Igush::with_capacity(10_000);
+        assert_eq!(array.row_capacity(), 100);
+        assert!(array.capacity() >= 10_000);
+        assert!(array.is_empty());
+
+        let array: Igush<i32> = Igush::with_capacity(50);
+        assert_eq!(array.row_capacity(), DEFAULT_ROW_CAPACITY);
+        assert!(array.capacity() >= 50);
+
+        let array: Igush<i32> = Igush::with_capacity(0);
+        assert_eq!(array.row_capacity(), DEFAULT_ROW_CAPACITY);
+        assert_eq!(array.rows_capacity(), 0);
+    }
+
+    #[test]
+    fn with_capacity_exact_rows() {
+        for capacity in [100, 101, 199, 200, 10_000] {
+            let mut array: Igush<usize> = Igush::with_capacity(capacity);
+            let row_capacity = array.row_capacity();
+            assert_eq!(array.rows_capacity(), capacity.div_ceil(row_capacity));
+
+            let rows_capacity = array.rows_capacity();
+            let memory_usage = array.memory_usage();
+            array.extend_back(0..capacity);
+            assert_eq!(array.rows_capacity(), rows_capacity);
+            assert_eq!(array.memory_usage(), memory_usage);
+        }
+    }
+
+    #[test]
+    fn reserve_covers_row_vector() {
+        let mut array: Igush<i32> = Igush::with_row_capacity(10, 0);
+        array.reserve(10_000);
+
+        let rows_capacity = array.rows_capacity();
+        let capacity = array.capacity();
+        assert!(rows_capacity >= 1000);
+
+        array.extend_back(0..10_000);
+        assert_eq!(array.rows(), 1000);
+        assert_eq!(array.rows_capacity(), rows_capacity);
+        assert_eq!(array.capacity(), capacity);
+    }
+
+    #[test]
+    fn shrink_to_fit() {
+        let mut array: Igush<i32> = Igush::with_row_capacity(10, 0);
+        array.extend_back(0..10_000);
+        assert!(array.rows_capacity() >= 1000);
+
+        array.truncate(25);
+        assert!(array.rows_capacity() >= 1000);
+
+        array.shrink_to_fit();
+        assert_eq!(array.rows(), 3);
+        assert!(array.rows_capacity() < 10);
+        assert_eq!(array.capacity(), array.rows_capacity() * 10);
+        assert!(array.iter().copied().eq(0..25));
+    }
+
+    #[test]
+    fn iter_rows() {
+        let mut array: Igush<i32> = Igush::with_row_capacity(4, 20);
+        for i in (0..10).rev() {
+            array.push_front(i);
+        }
+
+        assert_eq!(array.iter_rows().len(), 3);
+        let lengths: Vec<usize> = array.iter_rows().map(|row| row.len()).collect();
+        assert_eq!(lengths, [4, 4, 2]);
+        assert!(array.iter_rows().flatten().eq(array.iter()));
+        assert!(array.iter_rows().next_back().unwrap().eq(&[8, 9]));
+
+        assert_eq!(Igush::<i32>::with_row_capacity(4, 20).iter_rows().len(), 0);
+    }
+
+    #[test]
+    fn swap_rows() {
+        let mut array: Igush<i32> = Igush::with_row_capacity(3, 0);
+        for i in (0..11).rev() {
+            array.push_front(i);
+        }
+
+        array.swap_rows(0, 2);
+        assert_eq!(array, [6, 7, 8, 3, 4, 5, 0, 1, 2, 9, 10]);
+        assert_eq!(array.get(6), Some(&0));
+
+        array.swap_rows(1, 1);
+        array.push_back(11);
+        array.swap_rows(3, 0);
+        assert_eq!(array, [9, 10, 11, 3, 4, 5, 0, 1, 2, 6, 7, 8]);
+        check_invariants(&array);
+    }
+
+    #[test]
+    #[should_panic(expected = "row out of bounds")]
+    fn swap_partial_row() {
+        let mut array: Igush<i32> = Igush::with_row_capacity(3, 0);
+        array.extend_back(0..5);
+
+        array.swap_rows(0, 1);
+    }
+
+    #[test]
+    fn debug() {
+        let mut array: Igush<i32> = Igush::with_row_capacity(3, 12);
+        for i in (0..5).rev() {
+            array.push_front(i);
+        }
+
+        assert_eq!(format!("{:?}", array), "[0, 1, 2, 3, 4]");
+        assert_eq!(
+            array.debug_structure(),
+            "Igush { row_capacity: 3, rows: 2, length: 5, \
+             backing: [[0, 1, 2], [3, 4], [], []] }"
+        );
+        assert_eq!(format!("{:?}", Igush::<i32>::new()), "[]");
+    }
+
+    #[test]
+    fn clone() {
+        // spare rows and spare room in the backing are not carried over
+        let mut array: Igush<i32> = Igush::with_row_capacity(4, 100);
+        array.extend_back(0..10);
+        array.backing.reserve(50);
+
+        let clone = array.clone();
+        check_invariants(&clone);
+        assert_eq!(clone, array);
+        assert_eq!(clone.rows(), 3);
+        assert!(clone.capacity() < array.capacity());
+
+        check_invariants(&Igush::<i32>::with_row_capacity(4, 100).clone());
+    }
+
+    #[test]
+    fn clone_from() {
+        let mut source: Igush<i32> = Igush::with_row_capacity(4, 0);
+        for i in (0..10).rev() {
+            source.push_front(i);
+        }
+
+        let mut array: Igush<i32> = Igush::with_row_capacity(4, 0);
+        array.extend_back(100..116);
+        let rows_capacity = array.rows_capacity();
+        let first_row = array.backing[0].as_slices().0.as_ptr();
+
+        array.clone_from(&source);
+        check_invariants(&array);
+        assert_eq!(array, source);
+        assert_eq!(array.rows_capacity(), rows_capacity);
+        assert_eq!(array.backing[0].as_slices().0.as_ptr(), first_row);
+
+        // cloning from a longer array adds the rows it needs
+        source.extend_back(10..30);
+        array.clone_from(&source);
+        check_invariants(&array);
+        assert_eq!(array, source);
+
+        array.clone_from(&Igush::new());
+        check_invariants(&array);
+        assert!(array.is_empty());
+        assert_eq!(array.row_capacity(), DEFAULT_ROW_CAPACITY);
+    }
+
+    #[test]
+    fn extend_copied() {
+        let mut array: Igush<i32> = Igush::with_row_capacity(2, 0);
+        let source = [1, 2, 3];
+
+        array.extend(&source);
+        array.extend(source.iter().rev());
+        assert_eq!(array, [1, 2, 3, 3, 2, 1]);
+        assert_eq!(source, [1, 2, 3]);
+    }
+
+    #[test]
+    fn from_iter_copied() {
+        let source = [1, 2, 3];
+        let array: Igush<i32> = source.iter().collect();
+        assert_eq!(array, [1, 2, 3]);
+
+        let long: Vec<usize> = (0..1000).collect();
+        let array: Igush<usize> = long.iter().collect();
+        assert_eq!(array.row_capacity(), 31);
+        assert!(array.iter().eq(long.iter()));
+    }
+
+    #[test]
+    fn insert_every_index() {
+        for rc in 1..5 {
+            for len in 0..13 {
+                for index in 0..=len {
+                    let mut array: Igush<usize> = Igush::with_row_capacity(rc, 0);
+                    array.extend_back(0..len);
+                    let mut model: Vec<usize> = (0..len).collect();
+
+                    array.insert(index, 100);
+                    model.insert(index, 100);
+                    check_invariants(&array);
+                    assert!(array.iter().eq(model.iter()));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn locate() {
+        let mut array: Igush<i32> = Igush::with_row_capacity(4, 0);
+        for i in (0..10).rev() {
+            array.push_front(i);
+        }
+
+        assert_eq!(array.locate(0), (0, 0));
+        assert_eq!(array.locate(3), (0, 3));
+        assert_eq!(array.locate(4), (1, 0));
+        assert_eq!(array.locate(9), (2, 1));
+
+        for index in 0..array.len() {
+            let (row, column) = array.locate(index);
+            let mut rows = array.iter_rows();
+            assert_eq!(rows.nth(row).unwrap().nth(column), array.get(index));
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds")]
+    fn out_of_bounds_locate() {
+        Igush::from([1, 2, 3]).locate(3);
+    }
+
+    #[test]
+    fn extend_from_slice() {
+        let mut array: Igush<String> = Igush::with_row_capacity(3, 0);
+        array.push_back("a".to_string());
+        array.push_back("b".to_string());
+
+        let source: Vec<String> = (0..8).map(|i| i.to_string()).collect();
+        array.extend_from_slice(&source);
+        check_invariants(&array);
+        assert_eq!(array.len(), 10);
+        assert!(array.iter().skip(2).eq(source.iter()));
+        assert_eq!(array[1], "b");
+
+        let rows_capacity = array.rows_capacity();
+        array.reserve(6);
+        let reserved = array.rows_capacity();
+        array.extend_from_slice(&source[..6]);
+        check_invariants(&array);
+        assert!(reserved >= rows_capacity);
+        assert_eq!(array.rows_capacity(), reserved);
+
+        array.extend_from_slice(&[]);
+        assert_eq!(array.len(), 16);
+    }
+
+    #[test]
+    fn equal_range() {
+        let mut array: Igush<i32> = Igush::with_row_capacity(3, 0);
+        for &x in [1, 2, 2, 2, 2, 3, 5, 5, 8].iter().rev() {
+            array.push_front(x);
+        }
+
+        assert_eq!(array.equal_range(&2), 1..5);
+        assert_eq!(array.equal_range(&5), 6..8);
+        assert_eq!(array.equal_range(&1), 0..1);
+        assert_eq!(array.equal_range(&8), 8..9);
+        assert_eq!(array.equal_range(&4), 6..6);
+        assert_eq!(array.equal_range(&0), 0..0);
+        assert_eq!(array.equal_range(&9), 9..9);
 
-        for i in 2..22 {
-            let num = 100 + (i as i32);
-            assert_eq!(array.get(i), Some(&num));
+        assert_eq!(Igush::<i32>::new().equal_range(&1), 0..0);
+    }
+
+    #[test]
+    fn min_max() {
+        let mut array: Igush<(i32, char)> = Igush::with_row_capacity(2, 0);
+        for &x in [(3, 'a'), (1, 'b'), (3, 'c'), (1, 'd'), (2, 'e')]
+            .iter()
+            .rev()
+        {
+            array.push_front(x);
         }
 
-        assert_eq!(array.get(22), Some(&2));
-        assert_eq!(array.get(23), Some(&3));
+        assert_eq!(array.max_element(), Some(&(3, 'c')));
+        assert_eq!(array.min_element(), Some(&(1, 'b')));
+        assert_eq!(array.max_element_by_key(|x| x.0), Some(&(3, 'c')));
+        assert_eq!(array.min_element_by_key(|x| x.0), Some(&(1, 'b')));
+        assert_eq!(array.max_element_by_key(|x| -x.0), Some(&(1, 'd')));
 
-        array.insert(0, 7);
+        let empty: Igush<i32> = Igush::new();
+        assert_eq!(empty.max_element(), None);
+        assert_eq!(empty.min_element(), None);
+        assert_eq!(empty.max_element_by_key(|&x| x), None);
+        assert_eq!(empty.min_element_by_key(|&x| x), None);
+    }
 
-        assert_eq!(array.get(0), Some(&7));
+    #[test]
+    fn sum_product() {
+        let mut array: Igush<i64> = Igush::with_row_capacity(2, 0);
+        array.push_back(3);
+        array.push_front(2);
+        array.push_back(4);
+        array.push_front(1);
+        array.push_back(5);
 
-        array.insert(array.len(), 8);
+        assert_eq!(array.sum::<i64>(), 15);
+        assert_eq!(array.product::<i64>(), 120);
 
-        assert_eq!(array.get(array.len() - 1), Some(&8));
+        let empty: Igush<f64> = Igush::new();
+        assert_eq!(empty.sum::<f64>(), 0.0);
+        assert_eq!(empty.product::<f64>(), 1.0);
     }
 
     #[test]
-    fn pop_back() {
-        let mut array: Igush<i32> = Igush::with_row_capacity(5, 0);
+    fn split_at() {
+        fn sum(slice: IgushSlice<'_, i32>) -> i32 {
+            match slice.len() {
+                0 => 0,
+                1 => slice[0],
+                len => {
+                    let (left, right) = slice.split_at(len / 2);
+                    sum(left) + sum(right)
+                }
+            }
+        }
 
-        assert_eq!(array.pop_back(), None);
+        let mut array: Igush<i32> = Igush::with_row_capacity(3, 0);
+        for i in (1..=20).rev() {
+            array.push_front(i);
+        }
 
-        for i in 0..20 {
-            array.push_back(i);
+        let (left, right) = array.split_at(7);
+        assert!(left.iter().copied().eq(1..=7));
+        assert!(right.iter().copied().eq(8..=20));
+        assert_eq!(sum(left) + sum(right), 210);
+        assert_eq!(array.split_at(0).0.len(), 0);
+        assert_eq!(array.split_at(20).1.len(), 0);
+
+        let (first, rest) = array.split_first().unwrap();
+        assert_eq!(*first, 1);
+        assert!(rest.iter().copied().eq(2..=20));
+        let (last, rest) = rest.split_last().unwrap();
+        assert_eq!(*last, 20);
+        assert!(rest.iter().copied().eq(2..=19));
+
+        let empty: Igush<i32> = Igush::new();
+        assert!(empty.split_first().is_none());
+        assert!(empty.split_last().is_none());
+    }
+
+    #[test]
+    #[should_panic(expected = "mid out of bounds")]
+    fn out_of_bounds_split_at() {
+        Igush::from([1, 2, 3]).split_at(4);
+    }
+
+    #[test]
+    fn into_vec() {
+        // a single unwrapped row hands over its buffer
+        let mut array: Igush<i32> = Igush::with_row_capacity(8, 0);
+        array.extend_back(0..5);
+        let buffer = array.backing[0].as_slices().0.as_ptr();
+        let vec = array.into_vec();
+        assert_eq!(vec, [0, 1, 2, 3, 4]);
+        assert_eq!(vec.as_ptr(), buffer);
+
+        let mut array: Igush<i32> = Igush::with_row_capacity(3, 0);
+        for i in (0..10).rev() {
+            array.push_front(i);
         }
+        assert_eq!(array.into_vec(), (0..10).collect::<Vec<_>>());
 
-        assert_eq!(array.len(), 20);
+        assert!(Igush::<i32>::new().into_vec().is_empty());
+        assert!(Igush::<i32>::with_row_capacity(3, 9).into_vec().is_empty());
+    }
 
-        assert_eq!(array.pop_back(), Some(19));
+    #[test]
+    fn drain_sorted() {
+        let mut array: Igush<i32> = Igush::with_row_capacity(3, 0);
+        for &x in [5, 3, 9, 1, 7, 3, 8, 2].iter() {
+            array.push_front(x);
+        }
 
-        assert_eq!(array.len(), 19);
+        assert!(array.drain_sorted().eq([1, 2, 3, 3, 5, 7, 8, 9]));
+        assert!(array.is_empty());
+        check_invariants(&array);
+
+        array.extend_back([4, 2, 6]);
+        let mut drained = array.drain_sorted();
+        assert_eq!(drained.next(), Some(2));
+        drop(drained);
+        assert!(array.is_empty());
+
+        array.push_back(1);
+        assert_eq!(array, [1]);
     }
 
     #[test]
-    fn pop_front() {
-        let mut array: Igush<i32> = Igush::with_row_capacity(5, 0);
+    fn into_rows() {
+        let mut array: Igush<i32> = Igush::with_row_capacity(4, 20);
+        for i in (0..10).rev() {
+            array.push_front(i);
+        }
 
-        assert_eq!(array.pop_front(), None);
+        let rows: Vec<Vec<i32>> = array.clone().into_rows().collect();
+        assert_eq!(rows, [vec![0, 1, 2, 3], vec![4, 5, 6, 7], vec![8, 9]]);
+        assert!(array.clone().into_rows().flatten().eq(array.into_iter()));
 
-        for i in 0..20 {
-            array.push_back(i);
+        let partial = Igush::from([1, 2]);
+        assert_eq!(partial.into_rows().collect::<Vec<_>>(), [vec![1, 2]]);
+        assert_eq!(Igush::<i32>::with_row_capacity(4, 20).into_rows().len(), 0);
+    }
+
+    #[test]
+    fn clear() {
+        let mut array: Igush<i32> = Igush::with_row_capacity(4, 0);
+        array.extend_back(0..100);
+        let capacity = array.capacity();
+
+        array.clear();
+        check_invariants(&array);
+        assert!(array.is_empty());
+        assert_eq!(array.capacity(), capacity);
+
+        array.extend_back(0..10);
+        assert!(array.iter().copied().eq(0..10));
+
+        array.clear_and_shrink();
+        check_invariants(&array);
+        assert!(array.is_empty());
+        assert_eq!(array.capacity(), 0);
+        assert_eq!(array.memory_usage(), 0);
+
+        array.push_back(1);
+        assert_eq!(array, [1]);
+    }
+
+    #[test]
+    fn hash_across_layouts() {
+        use std::collections::HashMap;
+
+        let mut a: Igush<String> = Igush::with_row_capacity(3, 0);
+        a.extend_back((0..20).map(|i| i.to_string()));
+
+        let mut b: Igush<String> = Igush::with_row_capacity(7, 30);
+        for i in (0..20).rev() {
+            b.push_front(i.to_string());
         }
 
-        assert_eq!(array.len(), 20);
+        // pushing to the front passes an element onto the front of every row,
+        // and a row filled from the back then wraps around inside its DEQ
+        let mut c: Igush<String> = Igush::with_row_capacity(4, 0);
+        c.extend_back((1..20).map(|i| i.to_string()));
+        c.push_front(0.to_string());
+        assert!(c.backing.iter().any(|row| !row.as_slices().1.is_empty()));
 
-        assert_eq!(array.pop_front(), Some(0));
+        for other in [&b, &c] {
+            assert_eq!(&a, other);
+            assert_eq!(hash_of(&a), hash_of(other));
+        }
 
-        assert_eq!(array.len(), 19);
+        let mut map = HashMap::new();
+        map.insert(a, "value");
+        assert_eq!(map.get(&b), Some(&"value"));
+        assert_eq!(map.get(&c), Some(&"value"));
     }
 
     #[test]
-    fn remove() {
-        let mut array: Igush<i32> = Igush::with_row_capacity(5, 0);
+    fn split_off_front() {
+        for rc in 1..5 {
+            for at in 0..=11 {
+                let mut array: Igush<i32> = Igush::with_row_capacity(rc, 0);
+                for i in (0..11).rev() {
+                    array.push_front(i);
+                }
+                let mut expected_back = array.clone();
+                let mut expected_front = expected_back.split_off(at);
+                mem::swap(&mut expected_front, &mut expected_back);
 
-        assert_eq!(array.remove(0), None);
+                let front = array.split_off_front(at);
+                check_invariants(&front);
+                check_invariants(&array);
+                assert_eq!(front, expected_front);
+                assert_eq!(array, expected_back);
+                assert!(front.iter().copied().eq(0..at as i32));
+                assert_eq!(front.row_capacity(), rc);
+            }
+        }
+    }
 
-        for i in 0..20 {
-            array.push_back(i);
+    #[test]
+    #[should_panic(expected = "at out of bounds")]
+    fn out_of_bounds_split_off_front() {
+        Igush::from([1, 2, 3]).split_off_front(4);
+    }
+
+    #[test]
+    fn rotate_wrapping() {
+        let mut array: Igush<i32> = Igush::with_row_capacity(3, 0);
+        array.extend_back(0..10);
+
+        array.rotate_left_wrapping(13);
+        assert_eq!(array, [3, 4, 5, 6, 7, 8, 9, 0, 1, 2]);
+        array.rotate_right_wrapping(1003);
+        assert!(array.iter().copied().eq(0..10));
+
+        array.rotate_left_wrapping(30);
+        array.rotate_right_wrapping(10);
+        assert!(array.iter().copied().eq(0..10));
+
+        let mut empty: Igush<i32> = Igush::new();
+        empty.rotate_left_wrapping(5);
+        empty.rotate_right_wrapping(usize::MAX);
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn index_of_sorted() {
+        let mut array: Igush<i32> = Igush::with_row_capacity(3, 0);
+        for &x in [1, 2, 2, 2, 2, 3, 5, 5, 5, 5, 5, 8].iter().rev() {
+            array.push_front(x);
         }
 
-        assert_eq!(array.len(), 20);
+        assert_eq!(array.index_of_sorted(&1), Some(0));
+        assert_eq!(array.index_of_sorted(&2), Some(1));
+        assert_eq!(array.index_of_sorted(&3), Some(5));
+        assert_eq!(array.index_of_sorted(&5), Some(6));
+        assert_eq!(array.index_of_sorted(&8), Some(11));
+        assert_eq!(array.index_of_sorted(&0), None);
+        assert_eq!(array.index_of_sorted(&4), None);
+        assert_eq!(array.index_of_sorted(&9), None);
+        assert_eq!(Igush::<i32>::new().index_of_sorted(&1), None);
+    }
 
-        assert_eq!(array.remove(10), Some(10));
+    #[test]
+    fn try_with_row_capacity() {
+        let error = Igush::<i32>::try_with_row_capacity(0, 10).unwrap_err();
+        assert_eq!(error, ZeroRowCapacity);
+        assert_eq!(error.to_string(), "row capacity must be non-zero");
 
-        assert_eq!(array.len(), 19);
+        let array = Igush::<i32>::try_with_row_capacity(5, 0).unwrap();
+        assert!(array.is_empty());
+        assert_eq!(array.row_capacity(), 5);
 
-        assert_eq!(array.remove(18), Some(19));
+        let array = Igush::<i32>::try_with_row_capacity(5, 12).unwrap();
+        assert!(array.capacity() >= 12);
+    }
 
-        assert_eq!(array.remove(0), Some(0));
+    #[test]
+    fn map() {
+        let mut array: Igush<i32> = Igush::with_row_capacity(3, 20);
+        for i in (0..10).rev() {
+            array.push_front(i);
+        }
 
-        assert_eq!(array.remove(20), None);
+        let mapped: Igush<String> = array.map(|x| format!("#{}", x));
+        check_invariants(&mapped);
+        assert_eq!(mapped.len(), 10);
+        assert_eq!(mapped.row_capacity(), 3);
+        assert!(mapped.iter().eq((0..10)
+            .map(|x| format!("#{}", x))
+            .collect::<Vec<_>>()
+            .iter()));
+
+        let mut mapped = mapped.map(|s| s.len());
+        mapped.push_back(0);
+        check_invariants(&mapped);
+        assert_eq!(mapped, [2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 0]);
+
+        assert!(Igush::<i32>::new().map(|x| x + 1).is_empty());
     }
 
     #[test]
-    fn equal() {
-        let mut a: Igush<i32> = Igush::with_row_capacity(5, 0);
+    fn retain_count() {
+        let mut array: Igush<i32> = Igush::with_row_capacity(3, 0);
+        array.extend_back(0..20);
+
+        assert_eq!(array.retain_count(|x| x % 4 != 0), 5);
+        assert_eq!(array.len(), 15);
+        assert_eq!(array.retain_count(|_| true), 0);
+        assert_eq!(array.retain_count(|_| false), 15);
+        assert!(array.is_empty());
+    }
+
+    #[test]
+    fn fill_range() {
+        let mut array: Igush<i32> = Igush::with_row_capacity(3, 0);
+        for i in (0..10).rev() {
+            array.push_front(i);
+        }
+
+        array.fill_range(2..7, -1);
+        assert_eq!(array, [0, 1, -1, -1, -1, -1, -1, 7, 8, 9]);
+        array.fill_range(4..=4, 4);
+        array.fill_range(..1, 100);
+        array.fill_range(9.., 200);
+        assert_eq!(array, [100, 1, -1, -1, 4, -1, -1, 7, 8, 200]);
+
+        array.fill_range(5..5, 0);
+        array.fill_range(.., 3);
+        assert_eq!(array, [3; 10]);
+    }
+
+    #[test]
+    #[should_panic(expected = "range end out of bounds")]
+    fn out_of_bounds_fill_range() {
+        Igush::from([1, 2, 3]).fill_range(1..4, 0);
+    }
+
+    #[test]
+    fn swap_contents() {
+        let mut a: Igush<i32> = Igush::with_row_capacity(3, 0);
+        a.extend_back(0..10);
         let mut b: Igush<i32> = Igush::with_row_capacity(5, 0);
+        b.extend_back(100..104);
+        b.set_adaptive(true);
 
-        for i in 0..20 {
-            a.push_back(i);
+        a.swap_contents(&mut b);
+        assert_eq!(a, [100, 101, 102, 103]);
+        assert_eq!(a.row_capacity(), 5);
+        assert!(a.is_adaptive());
+        assert!(b.iter().copied().eq(0..10));
+        assert_eq!(b.row_capacity(), 3);
+        assert!(!b.is_adaptive());
+    }
+
+    #[test]
+    fn partition_point() {
+        let mut array: Igush<i32> = Igush::with_row_capacity(3, 0);
+        for &x in [2, 4, 6, 8, 10, 1, 3, 5].iter().rev() {
+            array.push_front(x);
         }
-        for i in (0..20).rev() {
-            b.push_front(i);
+
+        assert_eq!(array.partition_point(|x| x % 2 == 0), 5);
+        assert_eq!(array.partition_point(|_| true), 8);
+        assert_eq!(array.partition_point(|_| false), 0);
+        assert_eq!(Igush::<i32>::new().partition_point(|_| true), 0);
+
+        let sorted: Igush<i32> = (0..100).collect();
+        for i in 0..=100 {
+            assert_eq!(sorted.partition_point(|&x| x < i), i as usize);
         }
+    }
 
-        assert_eq!(a, b);
+    #[test]
+    fn stride() {
+        let mut array: Igush<i32> = Igush::with_row_capacity(7, 0);
+        for i in (0..1000).rev() {
+            array.push_front(i);
+        }
+
+        for step in [1, 2, 3, 7, 10, 999, 1000, 5000] {
+            assert!(array.stride(step).eq(array.iter().step_by(step)));
+            assert_eq!(array.stride(step).len(), array.iter().step_by(step).len());
+            assert!(array
+                .stride(step)
+                .rev()
+                .eq(array.iter().step_by(step).rev()));
+        }
+
+        let mut stride = array.stride(300);
+        assert_eq!(stride.next_back(), Some(&900));
+        assert_eq!(stride.next(), Some(&0));
+        assert_eq!(stride.len(), 2);
+
+        assert_eq!(Igush::<i32>::new().stride(3).next(), None);
     }
 
     #[test]
-    #[should_panic(expected = "index out of bounds")]
-    fn out_of_bounds_insert() {
-        let mut array: Igush<i32> = Igush::with_row_capacity(5, 0);
+    #[should_panic(expected = "step must be non-zero")]
+    fn zero_stride() {
+        Igush::from([1, 2, 3]).stride(0);
+    }
 
-        array.insert(1, 3);
+    #[test]
+    fn iter_nth() {
+        let mut array: Igush<i32> = Igush::with_row_capacity(4, 0);
+        for i in (0..30).rev() {
+            array.push_front(i);
+        }
+        let model: Vec<i32> = (0..30).collect();
+
+        for skip in 0..32 {
+            for n in 0..32 {
+                let mut iter = array.iter();
+                let mut expected = model.iter();
+                assert_eq!(iter.next_back(), expected.next_back());
+                assert_eq!(iter.nth(skip), expected.nth(skip));
+                assert_eq!(iter.nth(n), expected.nth(n));
+                assert_eq!(iter.len(), expected.len());
+                assert!(iter.clone().eq(expected.clone()));
+                assert_eq!(iter.next_back(), expected.next_back());
+
+                let mut iter_mut = array.iter_mut();
+                let mut expected = model.iter();
+                assert_eq!(iter_mut.nth(skip).copied(), expected.nth(skip).copied());
+                assert_eq!(iter_mut.nth(n).copied(), expected.nth(n).copied());
+                assert_eq!(iter_mut.len(), expected.len());
+                assert!(iter_mut.map(|x| *x).eq(expected.copied()));
+            }
+        }
+
+        assert!(array.iter().step_by(7).copied().eq((0..30).step_by(7)));
+    }
+
+    #[test]
+    fn get_unchecked() {
+        let mut array: Igush<i32> = Igush::with_row_capacity(3, 20);
+        for i in (0..10).rev() {
+            array.push_front(i);
+        }
+
+        for index in 0..array.len() {
+            // SAFETY: the index is less than the length
+            unsafe {
+                assert_eq!(array.get_unchecked(index), &(index as i32));
+                *array.get_unchecked_mut(index) *= 2;
+            }
+        }
+        assert!(array.iter().copied().eq((0..20).step_by(2)));
+    }
+
+    #[test]
+    fn reserve_rows_ahead() {
+        let mut array: Igush<i32> = Igush::with_row_capacity(4, 0);
+        array.extend_back(0..6);
+
+        array.reserve_rows(50);
+        let rows_capacity = array.rows_capacity();
+        assert!(rows_capacity >= 52);
+
+        for i in 0..200 {
+            array.push_front(i);
+        }
+        assert_eq!(array.rows(), 52);
+        assert_eq!(array.rows_capacity(), rows_capacity);
+    }
+
+    #[test]
+    fn vec_from() {
+        let mut array: Igush<i32> = Igush::with_row_capacity(3, 0);
+        for i in (0..10).rev() {
+            array.push_front(i);
+        }
+
+        let vec: Vec<i32> = array.clone().into();
+        assert_eq!(vec, (0..10).collect::<Vec<_>>());
+        assert_eq!(Vec::from(array), vec);
+        assert_eq!(Vec::from(Igush::<i32>::new()), Vec::<i32>::new());
+    }
+
+    #[test]
+    fn ordering() {
+        let vecs: Vec<Vec<i32>> = vec![
+            vec![],
+            vec![1],
+            vec![1, 2, 3],
+            vec![1, 2, 3, 4],
+            vec![1, 2, 3, 4, 5, 6, 7],
+            vec![1, 2, 3, 4, 5, 6, 8],
+            vec![1, 2, 4],
+            vec![2],
+        ];
+
+        for a in &vecs {
+            for b in &vecs {
+                for (rc_a, rc_b) in [(3, 3), (2, 3), (1, 4)] {
+                    let mut x: Igush<i32> = Igush::with_row_capacity(rc_a, 0);
+                    let mut y: Igush<i32> = Igush::with_row_capacity(rc_b, 0);
+                    for &e in a.iter().rev() {
+                        x.push_front(e);
+                    }
+                    y.extend_back(b.iter().copied());
+
+                    assert_eq!(x.cmp(&y), a.cmp(b));
+                    assert_eq!(x.partial_cmp(&y), a.partial_cmp(b));
+                }
+            }
+        }
+
+        let nan: Igush<f64> = Igush::from([1.0, f64::NAN]);
+        assert_eq!(nan.partial_cmp(&nan), None);
+        let (short, long): (Igush<f64>, Igush<f64>) = ([1.0, 2.0].into(), [1.0, 2.0, 0.0].into());
+        assert!(short < long);
+
+        let mut large: Igush<u32> = (0..10_000).collect();
+        let other = large.clone();
+        *large.get_mut(9_999).unwrap() = 0;
+        assert!(large < other);
+        assert_eq!(large.max(other.clone()), other);
+    }
+
+    #[test]
+    fn from_parts() {
+        let mut array: Igush<i32> = Igush::with_row_capacity(3, 15);
+        for i in (0..8).rev() {
+            array.push_front(i);
+        }
+
+        let (backing, row_capacity) = array.clone().into_parts();
+        assert_eq!(row_capacity, 3);
+        let rebuilt = Igush::from_parts(backing.clone(), row_capacity).unwrap();
+        check_invariants(&rebuilt);
+        assert_eq!(rebuilt, array);
+
+        // SAFETY: the rows came straight from a valid array
+        let rebuilt = unsafe { Igush::from_raw_parts(backing, row_capacity) };
+        check_invariants(&rebuilt);
+        assert_eq!(rebuilt, array);
+
+        let rows = |lengths: &[usize]| -> Vec<VecDeque<i32>> {
+            lengths.iter().map(|&n| (0..n as i32).collect()).collect()
+        };
+        assert!(Igush::from_parts(rows(&[3, 3, 1, 0, 0]), 3).is_ok());
+        assert!(Igush::from_parts(rows(&[3, 3, 3]), 3).is_ok());
+        assert!(Igush::from_parts(rows(&[0, 0]), 3).unwrap().is_empty());
+        assert!(Igush::from_parts(rows(&[]), 3).unwrap().is_empty());
+
+        assert_eq!(Igush::from_parts(rows(&[3, 2, 2]), 3), Err(InvalidLayout));
+        assert_eq!(Igush::from_parts(rows(&[3, 0, 2]), 3), Err(InvalidLayout));
+        assert_eq!(Igush::from_parts(rows(&[3, 4]), 3), Err(InvalidLayout));
+        assert_eq!(
+            Igush::from_parts(rows(&[3, 3, 5, 0]), 3),
+            Err(InvalidLayout)
+        );
+        assert_eq!(Igush::from_parts(rows(&[1]), 0), Err(InvalidLayout));
+        assert_eq!(InvalidLayout.to_string(), "rows do not form a valid layout");
+
+        // trailing empty rows are kept as spare rows, not counted as in use
+        // SAFETY: every row before the last one holding elements is full
+        let rebuilt = unsafe { Igush::from_raw_parts(rows(&[3, 3, 1, 0, 0]), 3) };
+        check_invariants(&rebuilt);
+        assert_eq!(rebuilt.rows(), 3);
+        assert_eq!(rebuilt.len(), 7);
+    }
+
+    #[test]
+    fn capacity_includes_rows() {
+        let mut array: Igush<u32> = Igush::with_row_capacity(10, 1000);
+        assert_eq!(array.rows_capacity(), 100);
+        assert_eq!(array.capacity(), 1000);
+
+        array.extend_back(0..1005);
+        assert!(array.rows_capacity() > 100);
+        assert_eq!(array.capacity(), array.rows_capacity() * 10);
+        assert_eq!(
+            array.total_capacity(),
+            (array.capacity(), array.rows_capacity())
+        );
+        assert!(array.memory_usage() >= array.rows() * 10 * mem::size_of::<u32>());
+    }
+
+    #[test]
+    fn rows_mut() {
+        let mut array: Igush<i32> = Igush::with_row_capacity(4, 20);
+        for i in (0..10).rev() {
+            array.push_front(i);
+        }
+
+        let lengths: Vec<usize> = array.rows_mut().map(|row| row.len()).collect();
+        assert_eq!(lengths, [4, 4, 2]);
+        for (r, row) in array.rows_mut().enumerate() {
+            for element in row.iter_mut() {
+                *element += 100 * r as i32;
+            }
+        }
+        check_invariants(&array);
+        assert_eq!(array, [0, 1, 2, 3, 104, 105, 106, 107, 208, 209]);
+
+        array.rows_mut().next_back().unwrap().reverse();
+        assert_eq!(array, [0, 1, 2, 3, 104, 105, 106, 107, 209, 208]);
+        assert_eq!(Igush::<i32>::with_row_capacity(4, 20).rows_mut().len(), 0);
+    }
+
+    #[test]
+    fn absorb() {
+        for (front, back) in [(0, 7), (8, 12), (5, 0), (3, 9)] {
+            let mut appended: Igush<i32> = Igush::with_row_capacity(4, 0);
+            appended.extend_back(0..front);
+            let mut absorbed = appended.clone();
+
+            let mut other: Igush<i32> = Igush::with_row_capacity(4, 0);
+            other.extend_back(100..100 + back);
+            appended.append(&mut other.clone());
+            absorbed.absorb(other);
+
+            check_invariants(&absorbed);
+            assert_eq!(absorbed, appended);
+            assert!(absorbed
+                .iter()
+                .copied()
+                .eq((0..front).chain(100..100 + back)));
+        }
+
+        let mut array: Igush<String> = Igush::with_row_capacity(3, 0);
+        array.push_back("a".to_string());
+        let mut other: Igush<String> = Igush::with_row_capacity(5, 0);
+        other.extend_back(["b", "c"].iter().map(|s| s.to_string()));
+        array.absorb(other);
+        check_invariants(&array);
+        assert_eq!(array, ["a", "b", "c"]);
+    }
+
+    #[test]
+    fn split_truncate() {
+        let mut array: Igush<i32> = Igush::with_row_capacity(4, 0);
+        array.extend_back(0..10);
+
+        let tail = array.split_truncate(6);
+        check_invariants(&array);
+        check_invariants(&tail);
+        assert_eq!(array, [0, 1, 2, 3, 4, 5]);
+        assert_eq!(tail, [6, 7, 8, 9]);
+        assert_eq!(tail.row_capacity(), 4);
+
+        let tail = array.split_truncate(6);
+        assert!(tail.is_empty());
+        assert_eq!(tail.row_capacity(), 4);
+        assert!(array.split_truncate(20).is_empty());
+        assert_eq!(array.len(), 6);
+
+        let tail = array.split_truncate(0);
+        assert!(array.is_empty());
+        assert_eq!(tail, [0, 1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn extend_front() {
+        for length in [0, 1, 3, 4, 9, 12] {
+            for count in [0, 1, 3, 4, 5, 8, 11] {
+                let mut array: Igush<usize> = Igush::with_row_capacity(4, 0);
+                array.extend_back(0..length);
+                array.extend_front(100..100 + count);
+
+                check_invariants(&array);
+                assert!(array
+                    .iter()
+                    .copied()
+                    .eq((100..100 + count).chain(0..length)));
+            }
+        }
+
+        let mut array = Igush::new();
+        array.set_adaptive(true);
+        array.extend_back(0..10);
+        array.extend_front(100..1000);
+        check_invariants(&array);
+        assert!(array.row_capacity() > DEFAULT_ROW_CAPACITY);
+        assert!(array.iter().copied().eq((100..1000).chain(0..10)));
+    }
+
+    #[test]
+    fn extend_front_large() {
+        let mut array: Igush<usize> = Igush::with_row_capacity(100, 0);
+        array.extend_back(0..10_000);
+
+        let mut expected = array.clone();
+        for element in (10_000..11_000).rev() {
+            expected.push_front(element);
+        }
+        array.extend_front(10_000..11_000);
+
+        check_invariants(&array);
+        assert_eq!(array, expected);
+    }
+
+    #[test]
+    fn from_elem() {
+        let array = Igush::from_elem("x".to_string(), 23, 5);
+        check_invariants(&array);
+        assert_eq!(array.len(), 23);
+        assert_eq!(array.row_capacity(), 5);
+        assert_eq!(array.rows(), 5);
+        assert!(array.iter().all(|element| element == "x"));
+
+        let array = Igush::from_elem(7, 0, 3);
+        assert!(array.is_empty());
+        assert_eq!(array.row_capacity(), 3);
+    }
+
+    #[test]
+    #[should_panic(expected = "row capacity must be non-zero")]
+    fn from_elem_zero_row_capacity() {
+        Igush::from_elem(0, 4, 0);
+    }
+
+    #[test]
+    fn dedup_with_count() {
+        let mut array: Igush<i32> = Igush::with_row_capacity(3, 0);
+        array.extend_back(vec![1, 1, 1, 2, 3, 3, 1, 1, 1, 1, 1, 4]);
+        assert_eq!(
+            array.dedup_with_count(),
+            [(1, 3), (2, 1), (3, 2), (1, 5), (4, 1)]
+        );
+        check_invariants(&array);
+        assert_eq!(array, [1, 2, 3, 1, 4]);
+
+        let mut array: Igush<i32> = Igush::with_row_capacity(3, 0);
+        array.extend_back(vec![7; 10]);
+        assert_eq!(array.dedup_with_count(), [(7, 10)]);
+        check_invariants(&array);
+        assert_eq!(array, [7]);
+
+        let mut array: Igush<i32> = Igush::with_row_capacity(3, 0);
+        array.extend_back(0..10);
+        let runs = array.dedup_with_count();
+        assert!(runs.iter().copied().eq((0..10).map(|i| (i, 1))));
+        check_invariants(&array);
+        assert!(array.iter().copied().eq(0..10));
+
+        assert!(Igush::<i32>::new().dedup_with_count().is_empty());
+    }
+
+    #[test]
+    fn dedup_with_count_panic() {
+        #[derive(Clone, Debug)]
+        struct Fragile(i32);
+
+        impl PartialEq for Fragile {
+            fn eq(&self, other: &Self) -> bool {
+                assert!(self.0 != 3);
+                self.0 == other.0
+            }
+        }
+
+        let mut array: Igush<Fragile> = Igush::with_row_capacity(3, 0);
+        array.extend_back([1, 1, 2, 2, 3, 3, 4].iter().map(|&x| Fragile(x)));
+
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            array.dedup_with_count();
+        }));
+
+        assert!(result.is_err());
+        check_invariants(&array);
+        assert!(array.iter().map(|x| x.0).eq(vec![1, 2, 3, 3, 4]));
     }
 }
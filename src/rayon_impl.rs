@@ -0,0 +1,283 @@
+use alloc::collections::VecDeque;
+use core::slice;
+
+use rayon::iter::plumbing::{bridge, Consumer, Producer, ProducerCallback, UnindexedConsumer};
+use rayon::iter::{IndexedParallelIterator, IntoParallelIterator, ParallelIterator};
+
+use crate::{Igush, Iter};
+
+/// parallel iterator over references to the elements of an array
+pub struct ParIter<'a, T> {
+    array: &'a Igush<T>,
+}
+
+impl<'a, T: Sync> IntoParallelIterator for &'a Igush<T> {
+    type Item = &'a T;
+    type Iter = ParIter<'a, T>;
+
+    fn into_par_iter(self) -> ParIter<'a, T> {
+        ParIter { array: self }
+    }
+}
+
+impl<'a, T: Sync> ParallelIterator for ParIter<'a, T> {
+    type Item = &'a T;
+
+    fn drive_unindexed<C: UnindexedConsumer<&'a T>>(self, consumer: C) -> C::Result {
+        bridge(self, consumer)
+    }
+
+    fn opt_len(&self) -> Option<usize> {
+        Some(self.array.len())
+    }
+}
+
+impl<T: Sync> IndexedParallelIterator for ParIter<'_, T> {
+    fn len(&self) -> usize {
+        self.array.len()
+    }
+
+    fn drive<C: Consumer<Self::Item>>(self, consumer: C) -> C::Result {
+        bridge(self, consumer)
+    }
+
+    fn with_producer<CB: ProducerCallback<Self::Item>>(self, callback: CB) -> CB::Output {
+        callback.callback(IterProducer {
+            array: self.array,
+            start: 0,
+            end: self.array.len(),
+        })
+    }
+}
+
+/// splittable range of indices into an array
+struct IterProducer<'a, T> {
+    array: &'a Igush<T>,
+    start: usize,
+    end: usize,
+}
+
+impl<'a, T: Sync> Producer for IterProducer<'a, T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Iter<'a, T> {
+        Iter::new(
+            &self.array.backing,
+            self.array.row_capacity,
+            self.start,
+            self.end,
+        )
+    }
+
+    fn split_at(self, index: usize) -> (Self, Self) {
+        let mid = self.start + index;
+
+        (
+            IterProducer {
+                array: self.array,
+                start: self.start,
+                end: mid,
+            },
+            IterProducer {
+                array: self.array,
+                start: mid,
+                end: self.end,
+            },
+        )
+    }
+}
+
+/// parallel iterator over mutable references to the elements of an array
+pub struct ParIterMut<'a, T> {
+    array: &'a mut Igush<T>,
+}
+
+impl<'a, T: Send> IntoParallelIterator for &'a mut Igush<T> {
+    type Item = &'a mut T;
+    type Iter = ParIterMut<'a, T>;
+
+    fn into_par_iter(self) -> ParIterMut<'a, T> {
+        ParIterMut { array: self }
+    }
+}
+
+impl<'a, T: Send> ParallelIterator for ParIterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn drive_unindexed<C: UnindexedConsumer<&'a mut T>>(self, consumer: C) -> C::Result {
+        bridge(self, consumer)
+    }
+
+    fn opt_len(&self) -> Option<usize> {
+        Some(self.array.len())
+    }
+}
+
+impl<T: Send> IndexedParallelIterator for ParIterMut<'_, T> {
+    fn len(&self) -> usize {
+        self.array.len()
+    }
+
+    fn drive<C: Consumer<Self::Item>>(self, consumer: C) -> C::Result {
+        bridge(self, consumer)
+    }
+
+    fn with_producer<CB: ProducerCallback<Self::Item>>(self, callback: CB) -> CB::Output {
+        let length = self.array.len();
+        let row_capacity = self.array.row_capacity;
+        let rows = &mut self.array.backing[..self.array.rows];
+
+        // rows can only be split into disjoint slices if they don't wrap around
+        for row in rows.iter_mut() {
+            row.make_contiguous();
+        }
+
+        callback.callback(IterMutProducer {
+            first: &mut [],
+            rows,
+            last: &mut [],
+            row_capacity,
+            length,
+        })
+    }
+}
+
+/// splittable run of elements: part of a row, some whole rows, then part of a row
+///
+/// every row in `rows` is contiguous, and all but the last of them are full
+struct IterMutProducer<'a, T> {
+    first: &'a mut [T],
+    rows: &'a mut [VecDeque<T>],
+    last: &'a mut [T],
+    row_capacity: usize,
+    length: usize,
+}
+
+impl<'a, T: Send> Producer for IterMutProducer<'a, T> {
+    type Item = &'a mut T;
+    type IntoIter = ProducerIterMut<'a, T>;
+
+    fn into_iter(self) -> ProducerIterMut<'a, T> {
+        ProducerIterMut {
+            front: self.first.iter_mut(),
+            rows: self.rows.iter_mut(),
+            back: self.last.iter_mut(),
+            remaining: self.length,
+        }
+    }
+
+    fn split_at(self, index: usize) -> (Self, Self) {
+        let row_capacity = self.row_capacity;
+        let length = self.length;
+
+        let (left_first, left_rows, left_last, right_first, right_rows, right_last);
+        if index <= self.first.len() {
+            let (a, b) = self.first.split_at_mut(index);
+            left_first = a;
+            left_rows = &mut [][..];
+            left_last = &mut [][..];
+            right_first = b;
+            right_rows = self.rows;
+            right_last = self.last;
+        } else {
+            let offset = index - self.first.len();
+            let (row, column) = (offset / row_capacity, offset % row_capacity);
+
+            if row < self.rows.len() {
+                let (before, rest) = self.rows.split_at_mut(row);
+                let (split_row, after) = rest.split_first_mut().unwrap();
+                let (a, b) = split_row.as_mut_slices().0.split_at_mut(column);
+                left_first = self.first;
+                left_rows = before;
+                left_last = a;
+                right_first = b;
+                right_rows = after;
+                right_last = self.last;
+            } else {
+                let (a, b) = self
+                    .last
+                    .split_at_mut(offset - self.rows.len() * row_capacity);
+                left_first = self.first;
+                left_rows = self.rows;
+                left_last = a;
+                right_first = b;
+                right_rows = &mut [][..];
+                right_last = &mut [][..];
+            }
+        }
+
+        (
+            IterMutProducer {
+                first: left_first,
+                rows: left_rows,
+                last: left_last,
+                row_capacity,
+                length: index,
+            },
+            IterMutProducer {
+                first: right_first,
+                rows: right_rows,
+                last: right_last,
+                row_capacity,
+                length: length - index,
+            },
+        )
+    }
+}
+
+/// sequential iterator over one piece of a split `ParIterMut`
+struct ProducerIterMut<'a, T> {
+    front: slice::IterMut<'a, T>,
+    rows: slice::IterMut<'a, VecDeque<T>>,
+    back: slice::IterMut<'a, T>,
+    remaining: usize,
+}
+
+impl<'a, T> Iterator for ProducerIterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<&'a mut T> {
+        loop {
+            if let Some(element) = self.front.next() {
+                self.remaining -= 1;
+                return Some(element);
+            }
+
+            match self.rows.next() {
+                Some(row) => self.front = row.as_mut_slices().0.iter_mut(),
+                None => break,
+            }
+        }
+
+        let element = self.back.next()?;
+        self.remaining -= 1;
+        Some(element)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for ProducerIterMut<'a, T> {
+    fn next_back(&mut self) -> Option<&'a mut T> {
+        loop {
+            if let Some(element) = self.back.next_back() {
+                self.remaining -= 1;
+                return Some(element);
+            }
+
+            match self.rows.next_back() {
+                Some(row) => self.back = row.as_mut_slices().0.iter_mut(),
+                None => break,
+            }
+        }
+
+        let element = self.front.next_back()?;
+        self.remaining -= 1;
+        Some(element)
+    }
+}
+
+impl<T> ExactSizeIterator for ProducerIterMut<'_, T> {}
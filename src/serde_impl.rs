@@ -0,0 +1,20 @@
+use alloc::vec::Vec;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::Igush;
+
+/// serializes as a sequence of elements, front to back
+impl<T: Serialize> Serialize for Igush<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self)
+    }
+}
+
+/// deserializes from a sequence of elements, choosing the row capacity from its length
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for Igush<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let elements = Vec::<T>::deserialize(deserializer)?;
+        Ok(elements.into_iter().collect())
+    }
+}
@@ -0,0 +1,260 @@
+use core::iter::FusedIterator;
+use core::ops::Index;
+
+use crate::{Igush, Iter};
+
+/// borrowed view of a range of elements in an array
+///
+/// the elements may not be contiguous in memory, so this resolves
+/// indices through the array rather than being a slice itself
+pub struct IgushSlice<'a, T> {
+    /// the array being viewed
+    array: &'a Igush<T>,
+    /// index of the first element of the view in the array
+    start: usize,
+    /// number of elements in the view
+    length: usize,
+}
+
+impl<'a, T> IgushSlice<'a, T> {
+    /// view the elements from `start` up to `end` of the given array
+    pub(crate) fn new(array: &'a Igush<T>, start: usize, end: usize) -> Self {
+        IgushSlice {
+            array,
+            start,
+            length: end - start,
+        }
+    }
+
+    /// number of elements in the view
+    pub fn len(&self) -> usize {
+        self.length
+    }
+
+    /// returns true if the view is empty
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
+    /// retrieves an element in the view by index
+    pub fn get(&self, index: usize) -> Option<&'a T> {
+        if index >= self.length {
+            return None;
+        }
+
+        self.array.get(self.start + index)
+    }
+
+    /// split the view in two at the given index,
+    /// the first holding the elements before `mid` and the second the rest
+    ///
+    /// panics if `mid` is greater than the length of the view
+    pub fn split_at(&self, mid: usize) -> (Self, Self) {
+        assert!(mid <= self.length, "mid out of bounds");
+
+        let mid = self.start + mid;
+        (
+            IgushSlice::new(self.array, self.start, mid),
+            IgushSlice::new(self.array, mid, self.start + self.length),
+        )
+    }
+
+    /// returns the first element and a view of the rest, or `None` if the view is empty
+    pub fn split_first(&self) -> Option<(&'a T, Self)> {
+        let first = self.get(0)?;
+        let (_, rest) = self.split_at(1);
+
+        Some((first, rest))
+    }
+
+    /// returns the last element and a view of the rest, or `None` if the view is empty
+    pub fn split_last(&self) -> Option<(&'a T, Self)> {
+        let last = self.get(self.length.checked_sub(1)?)?;
+        let (rest, _) = self.split_at(self.length - 1);
+
+        Some((last, rest))
+    }
+
+    /// returns an iterator over the elements of the view, front to back
+    pub fn iter(&self) -> Iter<'a, T> {
+        Iter::new(
+            &self.array.backing,
+            self.array.row_capacity,
+            self.start,
+            self.start + self.length,
+        )
+    }
+}
+
+impl<T> Clone for IgushSlice<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for IgushSlice<'_, T> {}
+
+impl<T> Index<usize> for IgushSlice<'_, T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &T {
+        self.get(index).expect("index out of bounds")
+    }
+}
+
+impl<'a, T> IntoIterator for IgushSlice<'a, T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &IgushSlice<'a, T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
+
+/// iterator over consecutive non-overlapping views of an array,
+/// with a shorter final view if the length doesn't divide evenly
+pub struct Chunks<'a, T> {
+    /// the array being viewed
+    array: &'a Igush<T>,
+    /// index of the first element not yet yielded from the front
+    start: usize,
+    /// index after the last element not yet yielded from the back
+    end: usize,
+    /// number of elements in each view
+    size: usize,
+}
+
+impl<'a, T> Chunks<'a, T> {
+    /// view the given array `size` elements at a time
+    pub(crate) fn new(array: &'a Igush<T>, size: usize) -> Self {
+        Chunks {
+            array,
+            start: 0,
+            end: array.len(),
+            size,
+        }
+    }
+}
+
+impl<T> Clone for Chunks<'_, T> {
+    fn clone(&self) -> Self {
+        Chunks { ..*self }
+    }
+}
+
+impl<'a, T> Iterator for Chunks<'a, T> {
+    type Item = IgushSlice<'a, T>;
+
+    fn next(&mut self) -> Option<IgushSlice<'a, T>> {
+        if self.start == self.end {
+            return None;
+        }
+
+        let end = self.end.min(self.start + self.size);
+        let chunk = IgushSlice::new(self.array, self.start, end);
+        self.start = end;
+
+        Some(chunk)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = (self.end - self.start).div_ceil(self.size);
+
+        (remaining, Some(remaining))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for Chunks<'a, T> {
+    fn next_back(&mut self) -> Option<IgushSlice<'a, T>> {
+        if self.start == self.end {
+            return None;
+        }
+
+        // the last chunk is the short one, if any
+        let short = (self.end - self.start) % self.size;
+        let start = self.end - if short == 0 { self.size } else { short };
+        let chunk = IgushSlice::new(self.array, start, self.end);
+        self.end = start;
+
+        Some(chunk)
+    }
+}
+
+impl<T> ExactSizeIterator for Chunks<'_, T> {}
+impl<T> FusedIterator for Chunks<'_, T> {}
+
+/// iterator over overlapping views of consecutive elements of an array
+pub struct Windows<'a, T> {
+    /// the array being viewed
+    array: &'a Igush<T>,
+    /// index of the first element of the next view from the front
+    start: usize,
+    /// index after the last element of the next view from the back
+    end: usize,
+    /// number of elements in each view
+    size: usize,
+}
+
+impl<'a, T> Windows<'a, T> {
+    /// view every run of `size` elements of the given array
+    pub(crate) fn new(array: &'a Igush<T>, size: usize) -> Self {
+        Windows {
+            array,
+            start: 0,
+            end: array.len(),
+            size,
+        }
+    }
+}
+
+impl<T> Clone for Windows<'_, T> {
+    fn clone(&self) -> Self {
+        Windows { ..*self }
+    }
+}
+
+impl<'a, T> Iterator for Windows<'a, T> {
+    type Item = IgushSlice<'a, T>;
+
+    fn next(&mut self) -> Option<IgushSlice<'a, T>> {
+        if self.end - self.start < self.size {
+            return None;
+        }
+
+        let window = IgushSlice::new(self.array, self.start, self.start + self.size);
+        self.start += 1;
+
+        Some(window)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = (self.end - self.start + 1).saturating_sub(self.size);
+
+        (remaining, Some(remaining))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for Windows<'a, T> {
+    fn next_back(&mut self) -> Option<IgushSlice<'a, T>> {
+        if self.end - self.start < self.size {
+            return None;
+        }
+
+        let window = IgushSlice::new(self.array, self.end - self.size, self.end);
+        self.end -= 1;
+
+        Some(window)
+    }
+}
+
+impl<T> ExactSizeIterator for Windows<'_, T> {}
+impl<T> FusedIterator for Windows<'_, T> {}